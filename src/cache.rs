@@ -0,0 +1,69 @@
+use crate::models::CompanyAttribute;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::time::Duration;
+
+/// 회사 하나에 대해 보강 사이트에서 가져온 결과
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CachedEnrichment {
+    pub attributes: Vec<CompanyAttribute>,
+    pub fetched_at: DateTime<Utc>,
+}
+
+impl CachedEnrichment {
+    pub fn is_fresh(&self, ttl: Duration, now: DateTime<Utc>) -> bool {
+        chrono::Duration::from_std(ttl).is_ok_and(|ttl| self.fetched_at + ttl > now)
+    }
+}
+
+/// 보강 결과를 사이트·정규화된 회사명 기준으로 저장하는 JSON 파일 캐시
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct EnrichmentCache {
+    sites: BTreeMap<String, BTreeMap<String, CachedEnrichment>>,
+}
+
+impl EnrichmentCache {
+    /// 파일이 없거나 읽을 수 없으면 빈 캐시로 시작
+    pub fn load(path: impl AsRef<Path>) -> Self {
+        let path = path.as_ref();
+        let Ok(text) = std::fs::read_to_string(path) else {
+            return Self::default();
+        };
+
+        serde_json::from_str(&text).unwrap_or_else(|e| {
+            eprintln!("❌ 캐시 읽기 실패 ({}): {}", path.display(), e);
+            Self::default()
+        })
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        let json = serde_json::to_string_pretty(self).map_err(std::io::Error::other)?;
+        std::fs::write(path, json)
+    }
+
+    pub fn get(&self, site: &str, company: &str) -> Option<&CachedEnrichment> {
+        self.sites.get(site)?.get(company)
+    }
+
+    /// `ttl` 안에 조회한 결과만 돌려줌
+    pub fn get_fresh(
+        &self,
+        site: &str,
+        company: &str,
+        ttl: Duration,
+        now: DateTime<Utc>,
+    ) -> Option<&CachedEnrichment> {
+        self.get(site, company)
+            .filter(|entry| entry.is_fresh(ttl, now))
+    }
+
+    pub fn insert(&mut self, site: &str, company: &str, entry: CachedEnrichment) {
+        self.sites
+            .entry(site.to_string())
+            .or_default()
+            .insert(company.to_string(), entry);
+    }
+}
@@ -0,0 +1,373 @@
+use crate::crawler::{
+    CrawlOutput, DetailCrawlConfig, DetailCrawler, JobCrawler, JobDetail, ListFilter,
+};
+use crate::description::extract_sections;
+use crate::fetcher::{FetcherKind, PageFetcher};
+use crate::health::PageStat;
+use crate::normalizers::{parse_experience, parse_region};
+use crate::pipeline::Crawler;
+use crate::utils::visible_text;
+use crate::{Error, Job, Result};
+use rayon::ThreadPoolBuilder;
+use rayon::prelude::*;
+use scraper::{ElementRef, Html, Selector};
+use std::time::Duration;
+
+#[derive(Debug, Clone)]
+pub struct AtsBoardConfig {
+    /// 회사별 채용 페이지 URL (예: "https://toss.career.greetinghr.com", "https://jobs.lever.co/sendbird")
+    pub boards: Vec<&'static str>,
+    pub thread_count: usize,
+    pub exclude_keywords: Vec<&'static str>,
+    /// "서울 강남·서초", "판교"처럼 지역 조건 지정. 비어 있으면 전체 지역
+    pub locations: Vec<&'static str>,
+    pub fetcher: FetcherKind,
+}
+
+impl Default for AtsBoardConfig {
+    fn default() -> Self {
+        Self {
+            boards: Vec::new(),
+            thread_count: 4,
+            exclude_keywords: Vec::new(),
+            locations: Vec::new(),
+            fetcher: FetcherKind::Chrome,
+        }
+    }
+}
+
+/// 채용 페이지를 호스팅하는 ATS 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AtsFlavor {
+    Greeting,
+    Ninehire,
+    Lever,
+    Greenhouse,
+}
+
+/// ATS마다 다른 공고 목록 마크업
+struct BoardSelectors {
+    /// 공고가 없어도 항상 있는 목록 영역. 페이지 로드는 이 요소로 확인함
+    board: &'static str,
+    card: &'static str,
+    title: &'static str,
+    /// 카드 자체가 a 태그가 아닐 때 링크를 찾을 selector
+    link: Option<&'static str>,
+    location: Option<&'static str>,
+    employment_type: Option<&'static str>,
+    /// 직군, 고용형태, 지역, 경력이 구분 없이 나열된 항목
+    info: Option<&'static str>,
+}
+
+impl AtsFlavor {
+    /// 채용 페이지 URL의 호스트로 ATS 종류를 판별
+    pub fn detect(url: &str) -> Option<Self> {
+        let host = host(url).to_lowercase();
+        if host.ends_with("greetinghr.com") {
+            Some(Self::Greeting)
+        } else if host.contains("ninehire") {
+            Some(Self::Ninehire)
+        } else if host.ends_with("lever.co") {
+            Some(Self::Lever)
+        } else if host.ends_with("greenhouse.io") {
+            Some(Self::Greenhouse)
+        } else {
+            None
+        }
+    }
+
+    fn selectors(&self) -> BoardSelectors {
+        match self {
+            Self::Greeting => BoardSelectors {
+                board: "#__next",
+                card: r#"a[href*="/o/"]"#,
+                title: r#"[class*="JobTitle"]"#,
+                link: None,
+                location: None,
+                employment_type: None,
+                info: Some(r#"[class*="JobInfo"]"#),
+            },
+            Self::Ninehire => BoardSelectors {
+                board: "main",
+                card: r#"a[href^="/job_posting/"]"#,
+                title: "h3",
+                link: None,
+                location: None,
+                employment_type: None,
+                info: Some("p span"),
+            },
+            Self::Lever => BoardSelectors {
+                board: "div.postings-wrapper",
+                card: "div.posting",
+                title: r#"h5[data-qa="posting-name"]"#,
+                link: Some("a.posting-title"),
+                location: Some("span.sort-by-location"),
+                employment_type: Some("span.sort-by-commitment"),
+                info: None,
+            },
+            Self::Greenhouse => BoardSelectors {
+                board: "div#main",
+                card: "div.opening",
+                title: "a",
+                link: Some("a"),
+                location: Some("span.location"),
+                employment_type: None,
+                info: None,
+            },
+        }
+    }
+
+    /// 회사명을 찾지 못했을 때 쓸 URL의 회사 식별자
+    fn company_slug(&self, url: &str) -> String {
+        match self {
+            Self::Greeting | Self::Ninehire => host(url).split('.').next().unwrap_or_default(),
+            Self::Lever | Self::Greenhouse => url
+                .split_once("://")
+                .map_or(url, |(_, rest)| rest)
+                .split('/')
+                .nth(1)
+                .unwrap_or_default(),
+        }
+        .to_string()
+    }
+}
+
+fn host(url: &str) -> &str {
+    let without_scheme = url.split_once("://").map_or(url, |(_, rest)| rest);
+    without_scheme.split(['/', '?']).next().unwrap_or_default()
+}
+
+fn origin(url: &str) -> String {
+    match url.split_once("://") {
+        Some((scheme, _)) => format!("{}://{}", scheme, host(url)),
+        None => host(url).to_string(),
+    }
+}
+
+fn element_text(element: ElementRef) -> String {
+    let text = element.text().collect::<Vec<_>>().join(" ");
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+pub struct AtsBoardClient {
+    config: AtsBoardConfig,
+}
+
+impl AtsBoardClient {
+    pub fn new(config: AtsBoardConfig) -> Self {
+        Self { config }
+    }
+
+    /// 채용 페이지 하나를 열어 공고 목록을 수집. 열린 공고가 없는 채용 페이지는 빈 목록
+    pub fn fetch_board(&self, page: &dyn PageFetcher, board_url: &str) -> Result<Vec<Job>> {
+        let flavor = AtsFlavor::detect(board_url).ok_or_else(|| {
+            Error::parse(self.site_name(), board_url, "지원하지 않는 채용 페이지")
+        })?;
+
+        page.navigate_to(board_url)?;
+        page.wait_for_element(flavor.selectors().board)?;
+        let html = page.get_content()?;
+        std::thread::sleep(Duration::from_millis(500));
+
+        Ok(self.parse_board(flavor, board_url, &html))
+    }
+
+    pub fn parse_board(&self, flavor: AtsFlavor, board_url: &str, html: &str) -> Vec<Job> {
+        let document = Html::parse_document(html);
+        let selectors = flavor.selectors();
+        let parse = |selector: &str| Selector::parse(selector).unwrap();
+
+        let company = Selector::parse(r#"meta[property="og:site_name"]"#)
+            .ok()
+            .and_then(|selector| {
+                document
+                    .select(&selector)
+                    .next()
+                    .and_then(|meta| meta.attr("content"))
+                    .map(|name| name.trim().to_string())
+            })
+            .filter(|name| !name.is_empty())
+            .unwrap_or_else(|| flavor.company_slug(board_url));
+
+        let card_selector = parse(selectors.card);
+        let title_selector = parse(selectors.title);
+        let link_selector = selectors.link.map(parse);
+        let location_selector = selectors.location.map(parse);
+        let employment_selector = selectors.employment_type.map(parse);
+        let info_selector = selectors.info.map(parse);
+
+        let select_text = |card: ElementRef, selector: Option<&Selector>| {
+            selector
+                .and_then(|selector| card.select(selector).next())
+                .map(element_text)
+        };
+
+        document
+            .select(&card_selector)
+            .filter_map(|card| {
+                let title = select_text(card, Some(&title_selector)).unwrap_or_default();
+                if title.is_empty() {
+                    return None;
+                }
+
+                let info: Vec<String> = info_selector
+                    .iter()
+                    .flat_map(|selector| card.select(selector).map(element_text))
+                    .filter(|text| !text.is_empty())
+                    .collect();
+
+                let location = select_text(card, location_selector.as_ref())
+                    .or_else(|| {
+                        info.iter()
+                            .find(|item| parse_region(item).sido.is_some())
+                            .cloned()
+                    })
+                    .unwrap_or_default();
+                let region = parse_region(&location);
+
+                let experience_years = info
+                    .iter()
+                    .find(|item| item.contains("경력") || item.contains("신입"))
+                    .cloned()
+                    .unwrap_or_default();
+                let employment_type = select_text(card, employment_selector.as_ref())
+                    .or_else(|| {
+                        info.iter()
+                            .find(|item| item.ends_with("직") || item.as_str() == "인턴")
+                            .cloned()
+                    })
+                    .unwrap_or_default();
+
+                let href = match &link_selector {
+                    Some(selector) => card.select(selector).next()?.attr("href")?,
+                    None => card.attr("href")?,
+                };
+                let url = if href.starts_with("http") {
+                    href.to_string()
+                } else {
+                    format!("{}{}", origin(board_url), href)
+                };
+
+                Some(Job {
+                    title,
+                    company: company.clone(),
+                    experience: parse_experience(&experience_years),
+                    experience_years,
+                    location,
+                    region,
+                    employment_type,
+                    url,
+                    ..Default::default()
+                })
+            })
+            .collect()
+    }
+}
+
+impl Default for AtsBoardClient {
+    fn default() -> Self {
+        Self::new(AtsBoardConfig::default())
+    }
+}
+
+impl JobCrawler for AtsBoardClient {
+    fn site_name(&self) -> &str {
+        "채용 페이지"
+    }
+
+    fn fetcher_kind(&self) -> FetcherKind {
+        self.config.fetcher.clone()
+    }
+
+    fn list_filter(&self) -> ListFilter {
+        ListFilter::new(&self.config.exclude_keywords, &self.config.locations)
+    }
+
+    fn wait_for_detail_page_load(&self, page: &dyn PageFetcher) -> Result<()> {
+        page.wait_for_element("body")
+    }
+}
+
+impl Crawler for AtsBoardClient {
+    fn start_crawl(&self) -> Result<CrawlOutput> {
+        let backend = self
+            .create_backend()
+            .inspect_err(|e| eprintln!("❌ 회사 채용 페이지 수집 실패: {}", e))?;
+
+        println!(
+            "회사 채용 페이지 {}곳 수집 시작..",
+            self.config.boards.len()
+        );
+
+        let pool = ThreadPoolBuilder::new()
+            .num_threads(self.config.thread_count)
+            .build()?;
+        let pages = self.create_pages(backend.as_ref(), self.config.thread_count)?;
+        let filter = self.list_filter();
+
+        let boards: Vec<(Vec<Job>, PageStat)> = pool.install(|| {
+            self.config
+                .boards
+                .par_iter()
+                .enumerate()
+                .map(|(index, board_url)| {
+                    let thread_index = rayon::current_thread_index().unwrap();
+                    let page = pages[&thread_index].as_ref();
+
+                    let result = self.fetch_board(page, board_url);
+                    let stat = PageStat::new(board_url, index + 1, result.as_ref().map(Vec::len));
+
+                    match result {
+                        Ok(cards) => {
+                            println!(
+                                "[Thread {:?}] 완료: {} ({}개)",
+                                thread_index,
+                                board_url,
+                                cards.len()
+                            );
+                            (filter.apply(cards), stat)
+                        }
+                        Err(e) => {
+                            eprintln!("[Thread {:?}] 실패: {}", thread_index, e);
+                            (Vec::new(), stat)
+                        }
+                    }
+                })
+                .collect()
+        });
+
+        let mut output = CrawlOutput::default();
+        for (jobs, stat) in boards {
+            output.jobs.extend(jobs);
+            output.pages.push(stat);
+        }
+
+        println!(
+            "\n✅ 회사 채용 페이지 {}개 채용공고 수집 완료",
+            output.jobs.len()
+        );
+        Ok(output)
+    }
+}
+
+impl DetailCrawler for AtsBoardClient {
+    fn fetch_job_detail(
+        &self,
+        page: &dyn PageFetcher,
+        job: &Job,
+        config: &DetailCrawlConfig,
+    ) -> Result<Option<Job>> {
+        let document = self.open_detail(page, &job.url)?;
+
+        if !config.includes_any(&visible_text(&document)) {
+            return Ok(None);
+        }
+
+        let detail = JobDetail {
+            description: extract_sections(&document),
+            ..JobDetail::default()
+        };
+
+        Ok(Some(detail.apply(job)))
+    }
+}
@@ -0,0 +1,607 @@
+use crate::crawler::{
+    CrawlOutput, DetailCrawlConfig, DetailCrawler, JobCrawler, JobDetail,
+    JobListInfiniteScrollCrawler, JobListPaginatedCrawler, ListFilter,
+};
+use crate::description::extract_sections;
+use crate::fetcher::{FetcherKind, PageFetcher};
+use crate::normalizers::{
+    merge_tech_stack, normalize_tech_tags, parse_experience, parse_region, parse_salary,
+};
+use crate::pipeline::Crawler;
+use crate::utils::{
+    count_in, definition_items, definition_value, encode_query_value, last_page_number,
+    visible_text,
+};
+use crate::{Error, Job, Result};
+use regex::{Captures, Regex};
+use scraper::{ElementRef, Html, Selector};
+use serde::Deserialize;
+use std::path::Path;
+use std::time::Duration;
+
+#[derive(Debug, Clone)]
+pub struct ConfigurableCrawlConfig {
+    pub definition: SiteDefinition,
+    /// 목록 URL의 `{keyword}` 같은 자리표시자에 넣을 값. 쿼리 값으로 인코딩해서 넣음
+    pub variables: Vec<(&'static str, &'static str)>,
+    /// 최대 수집 페이지 수. None이면 사이트 정의의 마지막 페이지 규칙을 따름
+    pub total_pages: Option<usize>,
+    pub thread_count: usize,
+    pub exclude_keywords: Vec<&'static str>,
+    /// "서울 강남·서초", "판교"처럼 지역 조건 지정. 비어 있으면 전체 지역
+    pub locations: Vec<&'static str>,
+    pub fetcher: FetcherKind,
+}
+
+/// TOML/JSON 파일로 작성하는 채용 사이트 정의
+///
+/// selector가 바뀌거나 새 채용 사이트를 추가할 때 크레이트를 다시 빌드하지 않고
+/// 정의 파일만 고쳐서 쓸 수 있음. 예시는 `sites/` 디렉터리 참고
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SiteDefinition {
+    /// 로그와 오류 메시지에 쓰일 사이트 이름
+    pub name: String,
+    /// 상대 경로 링크 앞에 붙일 주소 (예: "https://www.saramin.co.kr")
+    pub base_url: String,
+    /// `{keyword}`처럼 중괄호 자리표시자를 쓸 수 있는 목록 페이지 URL.
+    /// `{tag?}`처럼 `?`를 붙인 자리표시자는 값이 없으면 그 쿼리 파라미터를 빼고 요청함
+    pub list_url: String,
+    #[serde(default)]
+    pub pagination: Pagination,
+    pub list: ListDefinition,
+    pub detail: Option<DetailDefinition>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case", deny_unknown_fields)]
+pub enum Pagination {
+    /// 목록 페이지 하나만 수집
+    #[default]
+    Single,
+    /// `param=n` 쿼리로 페이지 이동
+    Page {
+        param: String,
+        /// 첫 페이지 번호 (0부터 세는 사이트는 0)
+        #[serde(default = "default_first_page")]
+        first: usize,
+        /// 첫 페이지에서 마지막 페이지를 찾는 규칙
+        last_page: Option<LastPageRule>,
+    },
+    /// 스크롤해서 다음 공고를 불러옴 (Chrome fetcher 필요)
+    Scroll,
+}
+
+fn default_first_page() -> usize {
+    1
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LastPageRule {
+    /// 총 공고 수 요소 또는 페이지 목록 링크
+    pub selector: CssSelector,
+    /// 지정하면 selector를 총 공고 수로 보고 페이지당 공고 수로 나눔
+    pub per_page: Option<usize>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ListDefinition {
+    /// 목록 로드를 기다릴 selector. 없으면 card selector
+    pub wait_for: Option<String>,
+    /// 공고 카드 하나를 가리키는 selector
+    pub card: CssSelector,
+    pub fields: FieldRules,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DetailDefinition {
+    /// 상세 페이지 로드를 기다릴 selector. 없으면 body
+    pub wait_for: Option<String>,
+    #[serde(default)]
+    pub fields: FieldRules,
+}
+
+/// 공고 필드별 추출 규칙. 목록에서는 카드 안에서, 상세에서는 문서 전체에서 찾음
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FieldRules {
+    pub title: Option<FieldRule>,
+    pub company: Option<FieldRule>,
+    pub experience_years: Option<FieldRule>,
+    pub deadline: Option<FieldRule>,
+    pub location: Option<FieldRule>,
+    pub salary: Option<FieldRule>,
+    pub employment_type: Option<FieldRule>,
+    /// 일치하는 요소를 모두 기술 스택으로 읽음
+    pub tech_stack: Option<FieldRule>,
+    pub url: Option<FieldRule>,
+}
+
+/// 정의 파일을 읽을 때 미리 검사해 두는 CSS selector
+#[derive(Debug, Clone, Deserialize)]
+#[serde(try_from = "String")]
+pub struct CssSelector {
+    source: String,
+    selector: Selector,
+}
+
+impl CssSelector {
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+}
+
+impl TryFrom<String> for CssSelector {
+    type Error = String;
+
+    fn try_from(source: String) -> std::result::Result<Self, Self::Error> {
+        let selector = Selector::parse(&source)
+            .map_err(|e| format!("잘못된 selector \"{}\": {}", source, e))?;
+        Ok(Self { source, selector })
+    }
+}
+
+/// 필드 하나를 찾는 규칙
+///
+/// 문자열 하나만 쓰면 그 selector에 처음 일치하는 요소의 텍스트를 읽음.
+/// 표 형태로 쓰면 아래 항목을 조합할 수 있음
+/// - `selector`: CSS selector
+/// - `label`: `<dt>항목</dt><dd>값</dd>` 요약 정보의 항목 이름 (selector 대신 사용)
+/// - `attr`: 텍스트 대신 읽을 속성 (예: "href", "title")
+/// - `index`: selector에 일치하는 요소 중 몇 번째를 쓸지 (0부터)
+/// - `regex`: 읽은 값에서 첫 번째 그룹(없으면 일치한 부분 전체)만 남김.
+///   `index`가 없으면 regex에 맞는 첫 요소를 씀
+#[derive(Debug, Clone, Deserialize)]
+#[serde(try_from = "FieldRuleSpec")]
+pub struct FieldRule {
+    selector: Option<Selector>,
+    label: Option<String>,
+    attr: Option<String>,
+    index: Option<usize>,
+    regex: Option<Regex>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum FieldRuleSpec {
+    Selector(String),
+    Rule(FieldRuleTable),
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FieldRuleTable {
+    selector: Option<String>,
+    label: Option<String>,
+    attr: Option<String>,
+    index: Option<usize>,
+    regex: Option<String>,
+}
+
+impl TryFrom<FieldRuleSpec> for FieldRule {
+    type Error = String;
+
+    fn try_from(spec: FieldRuleSpec) -> std::result::Result<Self, Self::Error> {
+        let table = match spec {
+            FieldRuleSpec::Selector(selector) => FieldRuleTable {
+                selector: Some(selector),
+                label: None,
+                attr: None,
+                index: None,
+                regex: None,
+            },
+            FieldRuleSpec::Rule(table) => table,
+        };
+
+        if table.selector.is_none() && table.label.is_none() {
+            return Err("selector나 label 중 하나는 있어야 함".to_string());
+        }
+
+        let selector = table
+            .selector
+            .map(|source| CssSelector::try_from(source).map(|css| css.selector))
+            .transpose()?;
+        let regex = table
+            .regex
+            .map(|pattern| {
+                Regex::new(&pattern).map_err(|e| format!("잘못된 regex \"{}\": {}", pattern, e))
+            })
+            .transpose()?;
+
+        Ok(Self {
+            selector,
+            label: table.label,
+            attr: table.attr,
+            index: table.index,
+            regex,
+        })
+    }
+}
+
+impl FieldRule {
+    pub fn extract(&self, document: &Html) -> Option<String> {
+        if let Some(label) = &self.label {
+            return definition_value(document, label).and_then(|text| self.post_process(&text));
+        }
+
+        let selector = self.selector.as_ref()?;
+        let mut elements = document.select(selector);
+        match self.index {
+            Some(index) => self.read(elements.nth(index)?),
+            None => elements.find_map(|element| self.read(element)),
+        }
+    }
+
+    /// selector에 일치하는 모든 요소의 값. label이면 `<dd>`에 나열된 항목 하나하나
+    pub fn extract_all(&self, document: &Html) -> Vec<String> {
+        match (&self.selector, &self.label) {
+            (_, Some(label)) => definition_items(document, label)
+                .iter()
+                .filter_map(|item| self.post_process(item))
+                .collect(),
+            (Some(selector), None) => document
+                .select(selector)
+                .filter_map(|element| self.read(element))
+                .collect(),
+            (None, None) => Vec::new(),
+        }
+    }
+
+    fn read(&self, element: ElementRef) -> Option<String> {
+        let value = match &self.attr {
+            Some(attr) => element.attr(attr)?.to_string(),
+            None => element.text().collect::<Vec<_>>().join(" "),
+        };
+        self.post_process(&value)
+    }
+
+    fn post_process(&self, value: &str) -> Option<String> {
+        let value = value.split_whitespace().collect::<Vec<_>>().join(" ");
+        let value = match &self.regex {
+            Some(regex) => {
+                let captures = regex.captures(&value)?;
+                captures
+                    .get(1)
+                    .or_else(|| captures.get(0))?
+                    .as_str()
+                    .trim()
+                    .to_string()
+            }
+            None => value,
+        };
+        Some(value).filter(|value| !value.is_empty())
+    }
+}
+
+impl SiteDefinition {
+    /// 확장자로 형식(.json이면 JSON, 나머지는 TOML)을 정해 사이트 정의 파일을 읽음
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let name = path.display().to_string();
+        let text = std::fs::read_to_string(path).map_err(|e| Error::definition(&name, e))?;
+
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("json") => Self::parse_json(&name, &text),
+            _ => Self::parse_toml(&name, &text),
+        }
+    }
+
+    pub fn from_toml(text: &str) -> Result<Self> {
+        Self::parse_toml("TOML", text)
+    }
+
+    pub fn from_json(text: &str) -> Result<Self> {
+        Self::parse_json("JSON", text)
+    }
+
+    fn parse_toml(name: &str, text: &str) -> Result<Self> {
+        let definition: Self = toml::from_str(text).map_err(|e| Error::definition(name, e))?;
+        definition.validate(name)
+    }
+
+    fn parse_json(name: &str, text: &str) -> Result<Self> {
+        let definition: Self =
+            serde_json::from_str(text).map_err(|e| Error::definition(name, e))?;
+        definition.validate(name)
+    }
+
+    fn validate(self, name: &str) -> Result<Self> {
+        let fields = &self.list.fields;
+        if fields.title.is_none() || fields.url.is_none() {
+            return Err(Error::definition(
+                name,
+                "list.fields에 title과 url이 있어야 함",
+            ));
+        }
+        Ok(self)
+    }
+}
+
+pub struct ConfigurableClient {
+    config: ConfigurableCrawlConfig,
+}
+
+impl ConfigurableClient {
+    pub fn new(config: ConfigurableCrawlConfig) -> Self {
+        Self { config }
+    }
+
+    fn definition(&self) -> &SiteDefinition {
+        &self.config.definition
+    }
+
+    /// 목록 URL의 자리표시자에 인코딩한 값을 넣음. 선택 자리표시자(`{name?}`)의 값이 없거나 비어 있으면
+    /// 그 쿼리 파라미터를 빼고, 값을 지정하지 않은 필수 자리표시자가 있으면 정의 오류
+    pub fn build_url(&self) -> Result<String> {
+        let list_url = &self.definition().list_url;
+        let (path, query) = list_url.split_once('?').unwrap_or((list_url, ""));
+        let mut missing = Vec::new();
+
+        let (mut url, _) = self.fill_placeholders(path, &mut missing);
+        let params: Vec<String> = query
+            .split('&')
+            .filter(|param| !param.is_empty())
+            .filter_map(|param| match self.fill_placeholders(param, &mut missing) {
+                (param, true) => Some(param),
+                (_, false) => None,
+            })
+            .collect();
+        if !params.is_empty() {
+            url.push('?');
+            url.push_str(&params.join("&"));
+        }
+
+        if !missing.is_empty() {
+            return Err(Error::definition(
+                self.site_name(),
+                format!("list_url의 {} 값이 지정되지 않음", missing.join(", ")),
+            ));
+        }
+        Ok(url)
+    }
+
+    /// 자리표시자를 채운 문자열과, 선택 자리표시자가 모두 채워졌는지 여부
+    fn fill_placeholders(&self, template: &str, missing: &mut Vec<String>) -> (String, bool) {
+        let re = Regex::new(r"\{(\w+)(\?)?\}").unwrap();
+        let mut complete = true;
+        let filled = re.replace_all(template, |captures: &Captures| {
+            let name = &captures[1];
+            let optional = captures.get(2).is_some();
+            match self.config.variables.iter().find(|(key, _)| *key == name) {
+                Some((_, value)) if !(optional && value.is_empty()) => encode_query_value(value),
+                _ if optional => {
+                    complete = false;
+                    String::new()
+                }
+                _ => {
+                    missing.push(format!("{{{}}}", name));
+                    String::new()
+                }
+            }
+        });
+        (filled.into_owned(), complete)
+    }
+
+    fn absolute_url(&self, href: &str) -> String {
+        if href.starts_with("http") {
+            href.to_string()
+        } else {
+            format!("{}{}", self.definition().base_url, href)
+        }
+    }
+
+    /// 규칙으로 찾은 필드만 덮어쓰고, 기술 스택은 기존 값에 합침
+    fn apply_fields(&self, fields: &FieldRules, document: &Html, job: &mut Job) {
+        let extract =
+            |rule: &Option<FieldRule>| rule.as_ref().and_then(|rule| rule.extract(document));
+
+        if let Some(title) = extract(&fields.title) {
+            job.title = title;
+        }
+        if let Some(company) = extract(&fields.company) {
+            job.company = company;
+        }
+        if let Some(experience_years) = extract(&fields.experience_years) {
+            job.experience = parse_experience(&experience_years);
+            job.experience_years = experience_years;
+        }
+        if let Some(deadline) = extract(&fields.deadline) {
+            job.deadline = deadline;
+        }
+        if let Some(location) = extract(&fields.location) {
+            job.region = parse_region(&location);
+            job.location = location;
+        }
+        if let Some(salary) = extract(&fields.salary).and_then(|text| parse_salary(&text)) {
+            job.salary = Some(salary);
+        }
+        if let Some(employment_type) = extract(&fields.employment_type) {
+            job.employment_type = employment_type;
+        }
+        if let Some(url) = extract(&fields.url) {
+            job.url = self.absolute_url(&url);
+        }
+        if let Some(rule) = &fields.tech_stack {
+            merge_tech_stack(
+                &mut job.tech_stack,
+                normalize_tech_tags(rule.extract_all(document)),
+            );
+        }
+    }
+
+    fn parse_list(&self, html: &str) -> Vec<Job> {
+        let document = Html::parse_document(html);
+        let list = &self.definition().list;
+
+        document
+            .select(&list.card.selector)
+            .filter_map(|card| {
+                let card_fragment = Html::parse_fragment(&card.html());
+
+                let mut job = Job::default();
+                self.apply_fields(&list.fields, &card_fragment, &mut job);
+
+                (!job.title.is_empty()).then_some(job)
+            })
+            .collect()
+    }
+}
+
+impl JobCrawler for ConfigurableClient {
+    fn site_name(&self) -> &str {
+        &self.definition().name
+    }
+
+    fn fetcher_kind(&self) -> FetcherKind {
+        self.config.fetcher.clone()
+    }
+
+    fn list_filter(&self) -> ListFilter {
+        ListFilter::new(&self.config.exclude_keywords, &self.config.locations)
+    }
+
+    fn wait_for_list_page_load(&self, page: &dyn PageFetcher) -> Result<()> {
+        let list = &self.definition().list;
+        page.wait_for_element(list.wait_for.as_deref().unwrap_or(list.card.as_str()))
+    }
+
+    fn wait_for_detail_page_load(&self, page: &dyn PageFetcher) -> Result<()> {
+        let wait_for = self
+            .definition()
+            .detail
+            .as_ref()
+            .and_then(|detail| detail.wait_for.as_deref());
+        page.wait_for_element(wait_for.unwrap_or("body"))
+    }
+}
+
+impl JobListPaginatedCrawler for ConfigurableClient {
+    fn build_page_url(&self, base_url: &str, page: usize) -> String {
+        match &self.definition().pagination {
+            Pagination::Page { param, first, .. } => {
+                let separator = if base_url.contains('?') { '&' } else { '?' };
+                format!("{}{}{}={}", base_url, separator, param, page - 1 + first)
+            }
+            Pagination::Single | Pagination::Scroll => base_url.to_string(),
+        }
+    }
+
+    fn parse_html(&self, html: &str) -> Result<Vec<Job>> {
+        Ok(self.parse_list(html))
+    }
+
+    fn parse_last_page(&self, html: &str) -> Option<usize> {
+        let Pagination::Page {
+            last_page: Some(rule),
+            ..
+        } = &self.definition().pagination
+        else {
+            return None;
+        };
+
+        let document = Html::parse_document(html);
+        match rule.per_page {
+            Some(per_page) => count_in(&document, rule.selector.as_str())
+                .map(|count| count.div_ceil(per_page).max(1)),
+            None => last_page_number(&document, rule.selector.as_str()),
+        }
+    }
+}
+
+impl JobListInfiniteScrollCrawler for ConfigurableClient {
+    fn parse_html(&self, html: &str) -> Result<Vec<Job>> {
+        Ok(self.parse_list(html))
+    }
+
+    fn go_next_page(&self, page: &dyn PageFetcher) -> Result<()> {
+        page.evaluate("window.scrollTo(0, document.body.scrollHeight)")?;
+        std::thread::sleep(Duration::from_secs(2));
+        Ok(())
+    }
+}
+
+impl Crawler for ConfigurableClient {
+    fn start_crawl(&self) -> Result<CrawlOutput> {
+        let url = self
+            .build_url()
+            .inspect_err(|e| eprintln!("❌ {} 채용공고 수집 실패: {}", self.site_name(), e))?;
+        let backend = self
+            .create_backend()
+            .inspect_err(|e| eprintln!("❌ {} 채용공고 수집 실패: {}", self.site_name(), e))?;
+
+        println!("{} 채용공고 목록 수집 시작..", self.site_name());
+        let output = match &self.definition().pagination {
+            Pagination::Single => {
+                JobListPaginatedCrawler::fetch_all_jobs(self, backend.as_ref(), &url, Some(1), 1)
+            }
+            Pagination::Page { .. } => JobListPaginatedCrawler::fetch_all_jobs(
+                self,
+                backend.as_ref(),
+                &url,
+                self.config.total_pages,
+                self.config.thread_count,
+            ),
+            Pagination::Scroll => JobListInfiniteScrollCrawler::fetch_all_jobs(
+                self,
+                backend.as_ref(),
+                &url,
+                self.config.total_pages.unwrap_or(1),
+            ),
+        };
+
+        output
+            .inspect(|output| {
+                println!(
+                    "\n✅ {} {}개 채용공고 수집 완료",
+                    self.site_name(),
+                    output.jobs.len()
+                )
+            })
+            .inspect_err(|e| eprintln!("❌ {} 채용공고 수집 실패: {}", self.site_name(), e))
+    }
+}
+
+/// 목록 카드에서 읽은 텍스트 필드를 이어 붙인 값
+fn card_text(job: &Job) -> String {
+    [
+        job.title.as_str(),
+        job.company.as_str(),
+        job.experience_years.as_str(),
+        job.location.as_str(),
+        job.employment_type.as_str(),
+        &job.tech_stack.join(" "),
+    ]
+    .join(" ")
+}
+
+impl DetailCrawler for ConfigurableClient {
+    fn fetch_job_detail(
+        &self,
+        page: &dyn PageFetcher,
+        job: &Job,
+        config: &DetailCrawlConfig,
+    ) -> Result<Option<Job>> {
+        // 상세 페이지 규칙이 없으면 목록 카드에서 읽은 값으로 포함 키워드를 확인
+        let Some(detail) = &self.definition().detail else {
+            return Ok(config.includes_any(&card_text(job)).then(|| job.clone()));
+        };
+
+        let document = self.open_detail(page, &job.url)?;
+
+        if !config.includes_any(&visible_text(&document)) {
+            return Ok(None);
+        }
+
+        let mut updated_job = job.clone();
+        self.apply_fields(&detail.fields, &document, &mut updated_job);
+        let detail = JobDetail {
+            description: extract_sections(&document),
+            ..JobDetail::default()
+        };
+
+        Ok(Some(detail.apply(&updated_job)))
+    }
+}
@@ -0,0 +1,348 @@
+use crate::Result;
+use crate::crawler::{
+    CrawlOutput, DEFAULT_MAX_PAGES, DetailCrawlConfig, DetailCrawler, DetailLabels, JobCrawler,
+    JobDetail, JobFieldExtractor, JobListPaginatedCrawler, ListFilter,
+};
+use crate::fetcher::{FetcherKind, PageFetcher};
+use crate::models::{Job, Salary};
+use crate::normalizers::{find_salary, merge_tech_stack, normalize_tech};
+use crate::pipeline::Crawler;
+use crate::utils::{count_in, encode_query_value, last_page_number, visible_text};
+use scraper::{Html, Selector};
+
+const JOBS_PER_PAGE: usize = 20;
+
+const DETAIL_LABELS: DetailLabels = DetailLabels {
+    deadline: Some("마감일"),
+    salary: Some("급여"),
+    employment_type: Some("고용형태"),
+    tech_stack: None,
+};
+
+#[derive(Debug, Clone)]
+pub struct JobKoreaCrawlConfig {
+    pub keyword: &'static str,
+    pub duty: Option<JobKoreaDuty>,
+    pub min_years: Option<u8>,
+    pub max_years: Option<u8>,
+    /// 최대 수집 페이지 수. 첫 페이지에서 찾은 마지막 페이지가 더 작으면 그 페이지까지 수집.
+    /// None이면 찾은 마지막 페이지까지 모두 수집하고, 찾지 못하면 `DEFAULT_MAX_PAGES`까지 수집
+    pub total_pages: Option<usize>,
+    pub thread_count: usize,
+    pub exclude_keywords: Vec<&'static str>,
+    /// "서울 강남·서초", "판교"처럼 지역 조건 지정. 비어 있으면 전체 지역
+    pub locations: Vec<&'static str>,
+    pub fetcher: FetcherKind,
+}
+
+impl Default for JobKoreaCrawlConfig {
+    fn default() -> Self {
+        Self {
+            keyword: "프론트엔드",
+            duty: None,
+            min_years: None,
+            max_years: None,
+            total_pages: Some(DEFAULT_MAX_PAGES),
+            thread_count: 1,
+            exclude_keywords: Vec::new(),
+            locations: Vec::new(),
+            fetcher: FetcherKind::Chrome,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum JobKoreaDuty {
+    Backend,
+    Frontend,
+    Web,
+    App,
+    DataEngineer,
+}
+
+impl JobKoreaDuty {
+    pub fn to_code(&self) -> u32 {
+        match self {
+            Self::Backend => 1000229,
+            Self::Frontend => 1000230,
+            Self::Web => 1000231,
+            Self::App => 1000232,
+            Self::DataEngineer => 1000236,
+        }
+    }
+}
+
+pub struct JobKoreaClient {
+    base_url: String,
+    config: JobKoreaCrawlConfig,
+}
+
+impl JobKoreaClient {
+    pub fn new(config: JobKoreaCrawlConfig) -> Self {
+        Self {
+            base_url: "https://www.jobkorea.co.kr".to_string(),
+            config,
+        }
+    }
+
+    pub fn build_url(&self) -> String {
+        let mut url = format!(
+            "{}/Search/?stext={}&tabType=recruit",
+            self.base_url,
+            encode_query_value(self.config.keyword)
+        );
+        if let Some(duty) = self.config.duty {
+            url.push_str(&format!("&duty={}", duty.to_code()));
+        }
+        if self.config.min_years == Some(0) {
+            url.push_str("&careerType=1,2");
+        }
+        if let Some(min_years) = self.config.min_years.filter(|years| *years > 0) {
+            url.push_str(&format!("&careerMin={}", min_years));
+        }
+        if let Some(max_years) = self.config.max_years {
+            url.push_str(&format!("&careerMax={}", max_years));
+        }
+        url
+    }
+
+    /// 공고 본문은 GI_Read_Comt_Ifrm iframe 안에 있음
+    fn extract_content_url(&self, document: &Html) -> Option<String> {
+        let iframe_selector = Selector::parse(r"iframe#gib_frame").ok()?;
+        let src = document.select(&iframe_selector).next()?.attr("src")?;
+        Some(format!("{}{}", self.base_url, src))
+    }
+}
+
+impl Default for JobKoreaClient {
+    fn default() -> Self {
+        Self::new(JobKoreaCrawlConfig::default())
+    }
+}
+
+impl JobCrawler for JobKoreaClient {
+    fn site_name(&self) -> &str {
+        "잡코리아"
+    }
+
+    fn fetcher_kind(&self) -> FetcherKind {
+        self.config.fetcher.clone()
+    }
+
+    fn list_filter(&self) -> ListFilter {
+        ListFilter::new(&self.config.exclude_keywords, &self.config.locations)
+    }
+
+    fn wait_for_list_page_load(&self, page: &dyn PageFetcher) -> Result<()> {
+        page.wait_for_element(r"div.list-default")
+    }
+
+    fn wait_for_detail_page_load(&self, page: &dyn PageFetcher) -> Result<()> {
+        page.wait_for_element(r"div.tbRow")
+    }
+}
+
+impl JobListPaginatedCrawler for JobKoreaClient {
+    fn build_page_url(&self, base_url: &str, page: usize) -> String {
+        format!("{}&Page_No={}", base_url, page)
+    }
+
+    /// "총 N건" 검색 결과 수로 계산. 페이지 목록은 10개씩만 보이므로 "다음" 버튼이 없을 때만 사용
+    fn parse_last_page(&self, html: &str) -> Option<usize> {
+        let document = Html::parse_document(html);
+        count_in(&document, r"p.filter-text strong")
+            .map(|count| count.div_ceil(JOBS_PER_PAGE).max(1))
+            .or_else(|| {
+                last_page_number(
+                    &document,
+                    r"div.tplPagination li, div.tplPagination a.tplBtn",
+                )
+            })
+    }
+
+    fn parse_html(&self, html: &str) -> Result<Vec<Job>> {
+        let document = Html::parse_document(html);
+        let job_card_selector = Selector::parse(r"div.list-default li.list-post").unwrap();
+
+        let jobs = document
+            .select(&job_card_selector)
+            .map(|card| {
+                let card_fragment = Html::parse_fragment(&card.html());
+
+                let title = self.extract_title(&card_fragment).unwrap_or_default();
+
+                let company = self.extract_company(&card_fragment).unwrap_or_default();
+                let experience_years = self
+                    .extract_experience_years(&card_fragment)
+                    .unwrap_or_default();
+                let experience = self.extract_experience(&card_fragment);
+                let url = self.extract_url(&card_fragment).unwrap_or_default();
+                let deadline = self.extract_deadline(&card_fragment).unwrap_or_default();
+                let location = self.extract_location(&card_fragment).unwrap_or_default();
+                let region = self.extract_region(&card_fragment);
+                let salary = self.extract_salary(&card_fragment);
+                let tech_stack = self.extract_tech_stack(&card_fragment);
+
+                Job {
+                    title,
+                    company,
+                    experience_years,
+                    experience,
+                    url,
+                    deadline,
+                    location,
+                    region,
+                    salary,
+                    tech_stack,
+                    ..Default::default()
+                }
+            })
+            .collect();
+
+        Ok(jobs)
+    }
+}
+
+impl JobFieldExtractor for JobKoreaClient {
+    fn extract_title(&self, fragment: &Html) -> Option<String> {
+        let title_selector = Selector::parse(r"a.title").ok()?;
+        let title_el = fragment.select(&title_selector).next()?;
+        let title = title_el
+            .value()
+            .attr("title")
+            .map(str::to_string)
+            .unwrap_or_else(|| title_el.text().collect::<String>());
+        Some(title.trim().to_string())
+    }
+
+    fn extract_company(&self, fragment: &Html) -> Option<String> {
+        let company_selector = Selector::parse(r"a.name").ok()?;
+        let text = fragment
+            .select(&company_selector)
+            .next()?
+            .text()
+            .collect::<String>()
+            .trim()
+            .to_string();
+        Some(text)
+    }
+
+    fn extract_experience_years(&self, fragment: &Html) -> Option<String> {
+        let exp_selector = Selector::parse(r"p.option span.exp").ok()?;
+        let text = fragment
+            .select(&exp_selector)
+            .next()?
+            .text()
+            .collect::<String>()
+            .trim()
+            .to_string();
+        Some(text)
+    }
+
+    fn extract_url(&self, fragment: &Html) -> Option<String> {
+        let title_selector = Selector::parse(r"a.title").ok()?;
+        let href = fragment.select(&title_selector).next()?.attr("href")?;
+        let path = href.split('?').next().unwrap_or(href);
+        Some(format!("{}{}", self.base_url, path))
+    }
+
+    fn extract_deadline(&self, fragment: &Html) -> Option<String> {
+        let date_selector = Selector::parse(r"p.option span.date").ok()?;
+        let text = fragment
+            .select(&date_selector)
+            .next()?
+            .text()
+            .collect::<String>()
+            .trim()
+            .to_string();
+        Some(text)
+    }
+
+    fn extract_location(&self, fragment: &Html) -> Option<String> {
+        let loc_selector = Selector::parse(r"p.option span.loc").ok()?;
+        let text = fragment
+            .select(&loc_selector)
+            .next()?
+            .text()
+            .collect::<String>()
+            .trim()
+            .to_string();
+        Some(text)
+    }
+
+    fn extract_salary(&self, fragment: &Html) -> Option<Salary> {
+        let option_selector = Selector::parse(r"p.option span").ok()?;
+        find_salary(
+            fragment
+                .select(&option_selector)
+                .map(|span| span.text().collect::<String>()),
+        )
+    }
+
+    fn extract_tech_stack(&self, fragment: &Html) -> Vec<String> {
+        let Ok(etc_selector) = Selector::parse(r"p.etc") else {
+            return Vec::new();
+        };
+
+        let mut tech_stack = Vec::new();
+        for etc in fragment.select(&etc_selector) {
+            let tags = etc
+                .text()
+                .collect::<String>()
+                .split(',')
+                .filter_map(normalize_tech)
+                .map(str::to_string)
+                .collect::<Vec<_>>();
+            merge_tech_stack(&mut tech_stack, tags);
+        }
+        tech_stack
+    }
+}
+
+impl Crawler for JobKoreaClient {
+    fn start_crawl(&self) -> Result<CrawlOutput> {
+        let backend = self
+            .create_backend()
+            .inspect_err(|e| eprintln!("❌ 잡코리아 채용공고 수집 실패: {}", e))?;
+
+        println!("잡코리아 채용공고 목록 수집 시작..",);
+        self.fetch_all_jobs(
+            backend.as_ref(),
+            &self.build_url(),
+            self.config.total_pages,
+            self.config.thread_count,
+        )
+        .inspect(|output| println!("\n✅ 잡코리아 {}개 채용공고 수집 완료", output.jobs.len()))
+        .inspect_err(|e| eprintln!("❌ 잡코리아 채용공고 수집 실패: {}", e))
+    }
+}
+
+impl DetailCrawler for JobKoreaClient {
+    fn fetch_job_detail(
+        &self,
+        page: &dyn PageFetcher,
+        job: &Job,
+        config: &DetailCrawlConfig,
+    ) -> Result<Option<Job>> {
+        let document = self.open_detail(page, &job.url)?;
+        let content = match self.extract_content_url(&document) {
+            Some(content_url) => {
+                page.navigate_to(&content_url)?;
+                Html::parse_document(&page.get_content()?)
+            }
+            None => document.clone(),
+        };
+
+        let text = format!("{} {}", visible_text(&document), visible_text(&content));
+        if !config.includes_any(&text) {
+            return Ok(None);
+        }
+
+        let detail = JobDetail {
+            description: self.extract_description(&content),
+            ..JobDetail::from_definitions(&document, &DETAIL_LABELS)
+        };
+
+        Ok(Some(detail.apply(job)))
+    }
+}
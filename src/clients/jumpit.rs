@@ -0,0 +1,334 @@
+use crate::crawler::{
+    CrawlOutput, DetailCrawlConfig, DetailCrawler, DetailLabels, JobCrawler, JobDetail,
+    JobFieldExtractor, JobListInfiniteScrollCrawler, ListFilter,
+};
+use crate::fetcher::{FetcherKind, PageFetcher};
+use crate::models::Salary;
+use crate::normalizers::{merge_tech_stack, normalize_tech, parse_salary};
+use crate::pipeline::Crawler;
+use crate::utils::{definition_value, encode_query_value, visible_text};
+use crate::{Job, Result};
+use scraper::{Html, Selector};
+use std::time::Duration;
+
+const DETAIL_LABELS: DetailLabels = DetailLabels {
+    deadline: Some("마감일"),
+    salary: None,
+    employment_type: None,
+    tech_stack: Some("기술스택"),
+};
+
+#[derive(Debug, Clone)]
+pub struct JumpitCrawlConfig {
+    pub category: JumpitJobCategory,
+    /// "React", "Spring Boot"처럼 점핏 기술스택 태그로 검색 조건 지정. 비어 있으면 전체
+    pub tech_stacks: Vec<&'static str>,
+    pub min_years: u8,
+    pub max_years: u8,
+    /// 점핏 검색 조건의 지역 태그. 비어 있으면 전체 지역
+    pub location_tags: Vec<JumpitLocation>,
+    pub total_pages: usize,
+    pub thread_count: usize,
+    pub exclude_keywords: Vec<&'static str>,
+    /// "서울 강남·서초", "판교"처럼 지역 조건 지정. 비어 있으면 전체 지역
+    pub locations: Vec<&'static str>,
+    pub fetcher: FetcherKind,
+}
+
+impl Default for JumpitCrawlConfig {
+    fn default() -> Self {
+        Self {
+            category: JumpitJobCategory::Frontend,
+            tech_stacks: Vec::new(),
+            min_years: 0,
+            max_years: 5,
+            location_tags: Vec::new(),
+            total_pages: 1,
+            thread_count: 8,
+            exclude_keywords: Vec::new(),
+            locations: Vec::new(),
+            fetcher: FetcherKind::Chrome,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum JumpitJobCategory {
+    Backend,
+    Frontend,
+    FullStack,
+    Android,
+    IOS,
+    DataEngineer,
+}
+
+impl JumpitJobCategory {
+    pub fn to_code(&self) -> u32 {
+        match self {
+            Self::Backend => 1,
+            Self::Frontend => 2,
+            Self::FullStack => 3,
+            Self::Android => 4,
+            Self::IOS => 16,
+            Self::DataEngineer => 8,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum JumpitLocation {
+    Seoul,
+    Gyeonggi,
+    Incheon,
+    Daejeon,
+    Busan,
+}
+
+impl JumpitLocation {
+    pub fn to_code(&self) -> u32 {
+        match self {
+            Self::Seoul => 101000,
+            Self::Gyeonggi => 102000,
+            Self::Incheon => 108000,
+            Self::Daejeon => 105000,
+            Self::Busan => 106000,
+        }
+    }
+}
+
+pub struct JumpitClient {
+    base_url: String,
+    config: JumpitCrawlConfig,
+}
+
+impl JumpitClient {
+    pub fn new(config: JumpitCrawlConfig) -> Self {
+        Self {
+            base_url: "https://jumpit.saramin.co.kr".to_string(),
+            config,
+        }
+    }
+
+    pub fn build_url(&self) -> String {
+        let mut url = format!(
+            "{}/positions?jobCategory={}&career={}&career={}",
+            self.base_url,
+            self.config.category.to_code(),
+            self.config.min_years,
+            self.config.max_years
+        );
+        for tech_stack in &self.config.tech_stacks {
+            url.push_str(&format!("&techStack={}", encode_query_value(tech_stack)));
+        }
+        for location in &self.config.location_tags {
+            url.push_str(&format!("&locationTag={}", location.to_code()));
+        }
+        url.push_str("&sort=rsp_rate");
+        url
+    }
+
+    /// 카드 하단의 지역, 경력 항목 목록
+    fn location_career_items(&self, fragment: &Html) -> Vec<String> {
+        let Ok(selector) = Selector::parse(r#"ul[class*="location_career"] li"#) else {
+            return Vec::new();
+        };
+        fragment
+            .select(&selector)
+            .map(|li| li.text().collect::<String>().trim().to_string())
+            .filter(|text| !text.is_empty())
+            .collect()
+    }
+}
+
+impl Default for JumpitClient {
+    fn default() -> Self {
+        Self::new(JumpitCrawlConfig::default())
+    }
+}
+
+impl JobCrawler for JumpitClient {
+    fn site_name(&self) -> &str {
+        "점핏"
+    }
+
+    fn fetcher_kind(&self) -> FetcherKind {
+        self.config.fetcher.clone()
+    }
+
+    fn list_filter(&self) -> ListFilter {
+        ListFilter::new(&self.config.exclude_keywords, &self.config.locations)
+    }
+
+    fn wait_for_list_page_load(&self, page: &dyn PageFetcher) -> Result<()> {
+        page.wait_for_element(r#"a[href^="/position/"]"#)
+    }
+
+    fn wait_for_detail_page_load(&self, page: &dyn PageFetcher) -> Result<()> {
+        page.wait_for_element("dl")
+    }
+}
+
+impl JobListInfiniteScrollCrawler for JumpitClient {
+    fn parse_html(&self, html: &str) -> Result<Vec<Job>> {
+        let document = Html::parse_document(html);
+
+        let card_selector = Selector::parse(r#"a[href^="/position/"]"#).unwrap();
+
+        let jobs = document
+            .select(&card_selector)
+            .map(|card| {
+                let card_doc = Html::parse_fragment(&card.html());
+
+                let title = self.extract_title(&card_doc).unwrap_or_default();
+
+                let company = self.extract_company(&card_doc).unwrap_or_default();
+                let experience_years = self.extract_experience_years(&card_doc).unwrap_or_default();
+                let experience = self.extract_experience(&card_doc);
+                let deadline = self.extract_deadline(&card_doc).unwrap_or_default();
+                let location = self.extract_location(&card_doc).unwrap_or_default();
+                let region = self.extract_region(&card_doc);
+                let tech_stack = self.extract_tech_stack(&card_doc);
+
+                let url = card
+                    .attr("href")
+                    .map(|href| format!("{}{}", self.base_url, href))
+                    .unwrap_or_default();
+
+                Job {
+                    title,
+                    company,
+                    experience_years,
+                    experience,
+                    deadline,
+                    url,
+                    location,
+                    region,
+                    tech_stack,
+                    ..Default::default()
+                }
+            })
+            .collect();
+
+        Ok(jobs)
+    }
+
+    fn go_next_page(&self, page: &dyn PageFetcher) -> Result<()> {
+        page.evaluate("window.scrollTo(0, document.body.scrollHeight)")?;
+        std::thread::sleep(Duration::from_secs(2));
+        Ok(())
+    }
+}
+
+impl JobFieldExtractor for JumpitClient {
+    fn extract_title(&self, fragment: &Html) -> Option<String> {
+        let selector = Selector::parse(r#"h2[class*="position_card_info_title"]"#).ok()?;
+        let text = fragment
+            .select(&selector)
+            .next()?
+            .text()
+            .collect::<String>()
+            .trim()
+            .to_string();
+        Some(text)
+    }
+
+    fn extract_company(&self, fragment: &Html) -> Option<String> {
+        let selector = Selector::parse(r#"div[class*="position_card_info_company"] span"#).ok()?;
+        let text = fragment
+            .select(&selector)
+            .next()?
+            .text()
+            .collect::<String>()
+            .trim()
+            .to_string();
+        Some(text)
+    }
+
+    fn extract_experience_years(&self, fragment: &Html) -> Option<String> {
+        self.location_career_items(fragment)
+            .into_iter()
+            .find(|item| item.contains("경력") || item.contains("신입"))
+    }
+
+    fn extract_url(&self, _fragment: &Html) -> Option<String> {
+        // URL은 카드 a 태그의 href에서 추출하므로 여기서는 구현하지 않음
+        None
+    }
+
+    fn extract_deadline(&self, fragment: &Html) -> Option<String> {
+        let selector = Selector::parse(r#"span[class*="position_card_deadline"]"#).ok()?;
+        let text = fragment
+            .select(&selector)
+            .next()?
+            .text()
+            .collect::<String>()
+            .trim()
+            .to_string();
+        Some(text)
+    }
+
+    fn extract_location(&self, fragment: &Html) -> Option<String> {
+        self.location_career_items(fragment)
+            .into_iter()
+            .find(|item| !item.contains("경력") && !item.contains("신입"))
+    }
+
+    fn extract_salary(&self, fragment: &Html) -> Option<Salary> {
+        // 목록 카드에는 연봉 정보가 없고 상세 페이지 요약 정보에서만 찾을 수 있음
+        definition_value(fragment, "연봉").and_then(|text| parse_salary(&text))
+    }
+
+    fn extract_tech_stack(&self, fragment: &Html) -> Vec<String> {
+        let Ok(tag_selector) = Selector::parse(r#"ul[class*="position_card_info_skill"] li"#)
+        else {
+            return Vec::new();
+        };
+
+        let tags = fragment
+            .select(&tag_selector)
+            .map(|tag| tag.text().collect::<String>())
+            .filter_map(|text| normalize_tech(text.trim_start_matches(['·', ' '])))
+            .map(str::to_string);
+
+        let mut tech_stack = Vec::new();
+        merge_tech_stack(&mut tech_stack, tags);
+        tech_stack
+    }
+}
+
+impl Crawler for JumpitClient {
+    fn start_crawl(&self) -> Result<CrawlOutput> {
+        let url = self.build_url();
+        let backend = self
+            .create_backend()
+            .inspect_err(|e| eprintln!("❌ 점핏 채용공고 수집 실패: {}", e))?;
+
+        println!("점핏 채용공고 목록 수집 시작..",);
+        self.fetch_all_jobs(backend.as_ref(), &url, self.config.total_pages)
+            .inspect(|output| println!("\n✅ 점핏 채용공고 {}개 수집 완료", output.jobs.len()))
+            .inspect_err(|e| eprintln!("❌ 점핏 채용공고 수집 실패: {}", e))
+    }
+}
+
+impl DetailCrawler for JumpitClient {
+    fn fetch_job_detail(
+        &self,
+        page: &dyn PageFetcher,
+        job: &Job,
+        config: &DetailCrawlConfig,
+    ) -> Result<Option<Job>> {
+        let document = self.open_detail(page, &job.url)?;
+
+        if !config.includes_any(&visible_text(&document)) {
+            return Ok(None);
+        }
+
+        let detail = JobDetail {
+            salary: self.extract_salary(&document),
+            description: self.extract_description(&document),
+            ..JobDetail::from_definitions(&document, &DETAIL_LABELS)
+        };
+
+        Ok(Some(detail.apply(job)))
+    }
+}
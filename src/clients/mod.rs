@@ -1,5 +1,27 @@
+pub mod ats;
+pub mod configurable;
+pub mod jobkorea;
+pub mod jumpit;
+pub mod programmers;
+pub mod remember;
+pub mod rocketpunch;
 pub mod saramin;
 pub mod wanted;
 
-pub use saramin::{SaraminClient, SaraminCrawlConfig, SaraminJobCategory};
-pub use wanted::{WantedClient, WantedCrawlConfig, WantedJobCategory, WantedJobSubcategory};
+pub use ats::{AtsBoardClient, AtsBoardConfig, AtsFlavor};
+pub use configurable::{ConfigurableClient, ConfigurableCrawlConfig, SiteDefinition};
+pub use jobkorea::{JobKoreaClient, JobKoreaCrawlConfig, JobKoreaDuty};
+pub use jumpit::{JumpitClient, JumpitCrawlConfig, JumpitJobCategory, JumpitLocation};
+pub use programmers::{ProgrammersClient, ProgrammersCrawlConfig, ProgrammersJobCategory};
+pub use remember::{RememberClient, RememberCrawlConfig};
+pub use rocketpunch::{
+    RocketpunchCareer, RocketpunchClient, RocketpunchCrawlConfig, RocketpunchJobRole,
+};
+pub use saramin::{
+    SaraminCareer, SaraminClient, SaraminCompanyType, SaraminCrawlConfig, SaraminEducation,
+    SaraminEmploymentType, SaraminJobCategory, SaraminRegion, SaraminSort,
+};
+pub use wanted::{
+    WantedClient, WantedCrawlConfig, WantedCrawlMode, WantedJobCategory, WantedJobSort,
+    WantedJobSubcategory, WantedLocation,
+};
@@ -0,0 +1,327 @@
+use crate::Result;
+use crate::crawler::{
+    CrawlOutput, DEFAULT_MAX_PAGES, DetailCrawlConfig, DetailCrawler, DetailLabels, JobCrawler,
+    JobDetail, JobFieldExtractor, JobListPaginatedCrawler, ListFilter,
+};
+use crate::fetcher::{FetcherKind, PageFetcher};
+use crate::models::{Job, Salary};
+use crate::normalizers::{merge_tech_stack, normalize_tech, parse_salary};
+use crate::pipeline::Crawler;
+use crate::utils::{count_in, encode_query_value, last_page_number, visible_text};
+use scraper::{Html, Selector};
+
+const JOBS_PER_PAGE: usize = 20;
+
+const DETAIL_LABELS: DetailLabels = DetailLabels {
+    deadline: Some("마감일"),
+    salary: Some("연봉"),
+    employment_type: Some("고용 형태"),
+    tech_stack: Some("기술 스택"),
+};
+
+#[derive(Debug, Clone)]
+pub struct ProgrammersCrawlConfig {
+    pub category: Option<ProgrammersJobCategory>,
+    /// 만원 단위 최소 연봉. 프로그래머스 검색 조건으로 전달됨
+    pub min_salary: Option<u32>,
+    pub min_years: Option<u8>,
+    pub max_years: Option<u8>,
+    /// "React", "Spring"처럼 프로그래머스 기술 태그로 검색 조건 지정. 비어 있으면 전체
+    pub tags: Vec<&'static str>,
+    /// 최대 수집 페이지 수. 첫 페이지에서 찾은 마지막 페이지가 더 작으면 그 페이지까지 수집.
+    /// None이면 찾은 마지막 페이지까지 모두 수집하고, 찾지 못하면 `DEFAULT_MAX_PAGES`까지 수집
+    pub total_pages: Option<usize>,
+    pub thread_count: usize,
+    pub exclude_keywords: Vec<&'static str>,
+    /// "서울 강남·서초", "판교"처럼 지역 조건 지정. 비어 있으면 전체 지역
+    pub locations: Vec<&'static str>,
+    pub fetcher: FetcherKind,
+}
+
+impl Default for ProgrammersCrawlConfig {
+    fn default() -> Self {
+        Self {
+            category: Some(ProgrammersJobCategory::Frontend),
+            min_salary: None,
+            min_years: None,
+            max_years: None,
+            tags: Vec::new(),
+            total_pages: Some(DEFAULT_MAX_PAGES),
+            thread_count: 1,
+            exclude_keywords: Vec::new(),
+            locations: Vec::new(),
+            fetcher: FetcherKind::Http,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum ProgrammersJobCategory {
+    Backend,
+    Frontend,
+    FullStack,
+    Android,
+    IOS,
+    DataEngineer,
+}
+
+impl ProgrammersJobCategory {
+    pub fn to_code(&self) -> u32 {
+        match self {
+            Self::Backend => 1,
+            Self::Frontend => 4,
+            Self::FullStack => 2,
+            Self::Android => 8,
+            Self::IOS => 9,
+            Self::DataEngineer => 5,
+        }
+    }
+}
+
+pub struct ProgrammersClient {
+    base_url: String,
+    config: ProgrammersCrawlConfig,
+}
+
+impl ProgrammersClient {
+    pub fn new(config: ProgrammersCrawlConfig) -> Self {
+        Self {
+            base_url: "https://career.programmers.co.kr".to_string(),
+            config,
+        }
+    }
+
+    pub fn build_url(&self) -> String {
+        let mut url = format!("{}/job?order=recent", self.base_url);
+        if let Some(category) = self.config.category {
+            url.push_str(&format!("&job_category_ids={}", category.to_code()));
+        }
+        if let Some(min_salary) = self.config.min_salary {
+            url.push_str(&format!("&min_salary={}", min_salary));
+        }
+        if let Some(min_years) = self.config.min_years {
+            url.push_str(&format!("&min_career={}", min_years));
+        }
+        if let Some(max_years) = self.config.max_years {
+            url.push_str(&format!("&max_career={}", max_years));
+        }
+        for tag in &self.config.tags {
+            url.push_str(&format!("&tags={}", encode_query_value(tag)));
+        }
+        url
+    }
+}
+
+impl Default for ProgrammersClient {
+    fn default() -> Self {
+        Self::new(ProgrammersCrawlConfig::default())
+    }
+}
+
+impl JobCrawler for ProgrammersClient {
+    fn site_name(&self) -> &str {
+        "프로그래머스"
+    }
+
+    fn fetcher_kind(&self) -> FetcherKind {
+        self.config.fetcher.clone()
+    }
+
+    fn list_filter(&self) -> ListFilter {
+        ListFilter::new(&self.config.exclude_keywords, &self.config.locations)
+    }
+
+    fn wait_for_list_page_load(&self, page: &dyn PageFetcher) -> Result<()> {
+        page.wait_for_element(r"ul.list-positions")
+    }
+
+    fn wait_for_detail_page_load(&self, page: &dyn PageFetcher) -> Result<()> {
+        page.wait_for_element(r"section.section-summary")
+    }
+}
+
+impl JobListPaginatedCrawler for ProgrammersClient {
+    fn build_page_url(&self, base_url: &str, page: usize) -> String {
+        format!("{}&page={}", base_url, page)
+    }
+
+    /// "총 N개" 포지션 수로 계산. 페이지 목록은 "다음" 링크가 없을 때만 사용
+    fn parse_last_page(&self, html: &str) -> Option<usize> {
+        let document = Html::parse_document(html);
+        count_in(&document, r"div.total-count strong")
+            .map(|count| count.div_ceil(JOBS_PER_PAGE).max(1))
+            .or_else(|| last_page_number(&document, r"ul.pagination a.page-link"))
+    }
+
+    fn parse_html(&self, html: &str) -> Result<Vec<Job>> {
+        let document = Html::parse_document(html);
+        let job_card_selector =
+            Selector::parse(r"ul.list-positions li.list-position-item").unwrap();
+
+        let jobs = document
+            .select(&job_card_selector)
+            .map(|card| {
+                let card_fragment = Html::parse_fragment(&card.html());
+
+                let title = self.extract_title(&card_fragment).unwrap_or_default();
+
+                let company = self.extract_company(&card_fragment).unwrap_or_default();
+                let experience_years = self
+                    .extract_experience_years(&card_fragment)
+                    .unwrap_or_default();
+                let experience = self.extract_experience(&card_fragment);
+                let url = self.extract_url(&card_fragment).unwrap_or_default();
+                let location = self.extract_location(&card_fragment).unwrap_or_default();
+                let region = self.extract_region(&card_fragment);
+                let salary = self.extract_salary(&card_fragment);
+                let tech_stack = self.extract_tech_stack(&card_fragment);
+
+                Job {
+                    title,
+                    company,
+                    experience_years,
+                    experience,
+                    url,
+                    location,
+                    region,
+                    salary,
+                    tech_stack,
+                    ..Default::default()
+                }
+            })
+            .collect();
+
+        Ok(jobs)
+    }
+}
+
+impl JobFieldExtractor for ProgrammersClient {
+    fn extract_title(&self, fragment: &Html) -> Option<String> {
+        let title_selector = Selector::parse(r"h5.position-title a").ok()?;
+        let text = fragment
+            .select(&title_selector)
+            .next()?
+            .text()
+            .collect::<String>()
+            .trim()
+            .to_string();
+        Some(text)
+    }
+
+    fn extract_company(&self, fragment: &Html) -> Option<String> {
+        let company_selector = Selector::parse(r"h6.company-name").ok()?;
+        let text = fragment
+            .select(&company_selector)
+            .next()?
+            .text()
+            .collect::<String>()
+            .trim()
+            .to_string();
+        Some(text)
+    }
+
+    fn extract_experience_years(&self, fragment: &Html) -> Option<String> {
+        let exp_selector = Selector::parse(r"ul.list-position-detail li.experience").ok()?;
+        let text = fragment
+            .select(&exp_selector)
+            .next()?
+            .text()
+            .collect::<String>()
+            .trim()
+            .to_string();
+        Some(text)
+    }
+
+    fn extract_url(&self, fragment: &Html) -> Option<String> {
+        let title_selector = Selector::parse(r"h5.position-title a").ok()?;
+        let href = fragment.select(&title_selector).next()?.attr("href")?;
+        Some(format!("{}{}", self.base_url, href))
+    }
+
+    fn extract_deadline(&self, _fragment: &Html) -> Option<String> {
+        // 목록 카드에는 마감일이 없고 상세 페이지 요약 정보에서만 찾을 수 있음
+        None
+    }
+
+    fn extract_location(&self, fragment: &Html) -> Option<String> {
+        let loc_selector = Selector::parse(r"ul.list-position-detail li.location").ok()?;
+        let text = fragment
+            .select(&loc_selector)
+            .next()?
+            .text()
+            .collect::<String>()
+            .trim()
+            .to_string();
+        Some(text)
+    }
+
+    fn extract_salary(&self, fragment: &Html) -> Option<Salary> {
+        let salary_selector = Selector::parse(r"ul.list-position-detail li.salary").ok()?;
+        let text = fragment
+            .select(&salary_selector)
+            .next()?
+            .text()
+            .collect::<String>();
+        parse_salary(&text)
+    }
+
+    fn extract_tech_stack(&self, fragment: &Html) -> Vec<String> {
+        let Ok(tag_selector) = Selector::parse(r"ul.list-position-tags li.stack-item") else {
+            return Vec::new();
+        };
+
+        let tags = fragment
+            .select(&tag_selector)
+            .filter_map(|tag| normalize_tech(&tag.text().collect::<String>()))
+            .map(str::to_string);
+
+        let mut tech_stack = Vec::new();
+        merge_tech_stack(&mut tech_stack, tags);
+        tech_stack
+    }
+}
+
+impl Crawler for ProgrammersClient {
+    fn start_crawl(&self) -> Result<CrawlOutput> {
+        let backend = self
+            .create_backend()
+            .inspect_err(|e| eprintln!("❌ 프로그래머스 채용공고 수집 실패: {}", e))?;
+
+        println!("프로그래머스 채용공고 목록 수집 시작..",);
+        self.fetch_all_jobs(
+            backend.as_ref(),
+            &self.build_url(),
+            self.config.total_pages,
+            self.config.thread_count,
+        )
+        .inspect(|output| {
+            println!(
+                "\n✅ 프로그래머스 {}개 채용공고 수집 완료",
+                output.jobs.len()
+            )
+        })
+        .inspect_err(|e| eprintln!("❌ 프로그래머스 채용공고 수집 실패: {}", e))
+    }
+}
+
+impl DetailCrawler for ProgrammersClient {
+    fn fetch_job_detail(
+        &self,
+        page: &dyn PageFetcher,
+        job: &Job,
+        config: &DetailCrawlConfig,
+    ) -> Result<Option<Job>> {
+        let document = self.open_detail(page, &job.url)?;
+
+        if !config.includes_any(&visible_text(&document)) {
+            return Ok(None);
+        }
+
+        let detail = JobDetail {
+            description: self.extract_description(&document),
+            ..JobDetail::from_definitions(&document, &DETAIL_LABELS)
+        };
+
+        Ok(Some(detail.apply(job)))
+    }
+}
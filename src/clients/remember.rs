@@ -0,0 +1,231 @@
+use crate::crawler::{
+    CrawlOutput, DetailCrawlConfig, DetailCrawler, DetailLabels, JobCrawler, JobDetail,
+    JobFieldExtractor, JobListInfiniteScrollCrawler, ListFilter,
+};
+use crate::fetcher::{FetcherKind, PageFetcher};
+use crate::models::Salary;
+use crate::normalizers::parse_salary;
+use crate::pipeline::Crawler;
+use crate::utils::{definition_value, encode_query_value, visible_text};
+use crate::{Job, Result};
+use scraper::{Html, Selector};
+use std::time::Duration;
+
+const DETAIL_LABELS: DetailLabels = DetailLabels {
+    deadline: None,
+    salary: None,
+    employment_type: Some("고용형태"),
+    tech_stack: None,
+};
+
+#[derive(Debug, Clone)]
+pub struct RememberCrawlConfig {
+    pub keyword: &'static str,
+    pub min_years: u8,
+    pub max_years: u8,
+    pub total_pages: usize,
+    pub thread_count: usize,
+    pub exclude_keywords: Vec<&'static str>,
+    /// "서울 강남·서초", "판교"처럼 지역 조건 지정. 비어 있으면 전체 지역
+    pub locations: Vec<&'static str>,
+    pub fetcher: FetcherKind,
+}
+
+impl Default for RememberCrawlConfig {
+    fn default() -> Self {
+        Self {
+            keyword: "프론트엔드",
+            min_years: 5,
+            max_years: 15,
+            total_pages: 1,
+            thread_count: 8,
+            exclude_keywords: Vec::new(),
+            locations: Vec::new(),
+            fetcher: FetcherKind::Chrome,
+        }
+    }
+}
+
+pub struct RememberClient {
+    base_url: String,
+    config: RememberCrawlConfig,
+}
+
+impl RememberClient {
+    pub fn new(config: RememberCrawlConfig) -> Self {
+        Self {
+            base_url: "https://career.rememberapp.co.kr".to_string(),
+            config,
+        }
+    }
+
+    pub fn build_url(&self) -> String {
+        format!(
+            "{}/job/postings?keyword={}&min_experience={}&max_experience={}",
+            self.base_url,
+            encode_query_value(self.config.keyword),
+            self.config.min_years,
+            self.config.max_years
+        )
+    }
+
+    fn card_text(&self, fragment: &Html, selector: &str) -> Option<String> {
+        let selector = Selector::parse(selector).ok()?;
+        let text = fragment
+            .select(&selector)
+            .next()?
+            .text()
+            .collect::<String>()
+            .trim()
+            .to_string();
+        Some(text).filter(|text| !text.is_empty())
+    }
+}
+
+impl Default for RememberClient {
+    fn default() -> Self {
+        Self::new(RememberCrawlConfig::default())
+    }
+}
+
+impl JobCrawler for RememberClient {
+    fn site_name(&self) -> &str {
+        "리멤버"
+    }
+
+    fn fetcher_kind(&self) -> FetcherKind {
+        self.config.fetcher.clone()
+    }
+
+    fn list_filter(&self) -> ListFilter {
+        ListFilter::new(&self.config.exclude_keywords, &self.config.locations)
+    }
+
+    fn wait_for_list_page_load(&self, page: &dyn PageFetcher) -> Result<()> {
+        page.wait_for_element(r#"a[href^="/job/posting/"]"#)
+    }
+
+    fn wait_for_detail_page_load(&self, page: &dyn PageFetcher) -> Result<()> {
+        page.wait_for_element("body")
+    }
+}
+
+impl JobListInfiniteScrollCrawler for RememberClient {
+    fn parse_html(&self, html: &str) -> Result<Vec<Job>> {
+        let document = Html::parse_document(html);
+
+        let card_selector = Selector::parse(r#"a[href^="/job/posting/"]"#).unwrap();
+
+        let jobs = document
+            .select(&card_selector)
+            .map(|card| {
+                let card_doc = Html::parse_fragment(&card.html());
+
+                let title = self.extract_title(&card_doc).unwrap_or_default();
+
+                let company = self.extract_company(&card_doc).unwrap_or_default();
+                let experience_years = self.extract_experience_years(&card_doc).unwrap_or_default();
+                let experience = self.extract_experience(&card_doc);
+                let deadline = self.extract_deadline(&card_doc).unwrap_or_default();
+                let location = self.extract_location(&card_doc).unwrap_or_default();
+                let region = self.extract_region(&card_doc);
+                let company_size = self.card_text(&card_doc, r#"li[class*="CompanySize"]"#);
+
+                let url = card
+                    .attr("href")
+                    .map(|href| format!("{}{}", self.base_url, href))
+                    .unwrap_or_default();
+
+                Job {
+                    title,
+                    company,
+                    experience_years,
+                    experience,
+                    deadline,
+                    url,
+                    location,
+                    region,
+                    company_size,
+                    ..Default::default()
+                }
+            })
+            .collect();
+
+        Ok(jobs)
+    }
+
+    fn go_next_page(&self, page: &dyn PageFetcher) -> Result<()> {
+        page.evaluate("window.scrollTo(0, document.body.scrollHeight)")?;
+        std::thread::sleep(Duration::from_secs(2));
+        Ok(())
+    }
+}
+
+impl JobFieldExtractor for RememberClient {
+    fn extract_title(&self, fragment: &Html) -> Option<String> {
+        self.card_text(fragment, r#"h4[class*="Title"]"#)
+    }
+
+    fn extract_company(&self, fragment: &Html) -> Option<String> {
+        self.card_text(fragment, r#"div[class*="CompanyName"]"#)
+    }
+
+    fn extract_experience_years(&self, fragment: &Html) -> Option<String> {
+        self.card_text(fragment, r#"li[class*="Career"]"#)
+    }
+
+    fn extract_url(&self, _fragment: &Html) -> Option<String> {
+        // URL은 카드 a 태그의 href에서 추출하므로 여기서는 구현하지 않음
+        None
+    }
+
+    fn extract_deadline(&self, fragment: &Html) -> Option<String> {
+        self.card_text(fragment, r#"span[class*="DueDate"]"#)
+    }
+
+    fn extract_location(&self, fragment: &Html) -> Option<String> {
+        self.card_text(fragment, r#"li[class*="Location"]"#)
+    }
+
+    fn extract_salary(&self, fragment: &Html) -> Option<Salary> {
+        // 목록 카드에는 연봉 정보가 없고 상세 페이지 요약 정보에서만 찾을 수 있음
+        definition_value(fragment, "연봉").and_then(|text| parse_salary(&text))
+    }
+}
+
+impl Crawler for RememberClient {
+    fn start_crawl(&self) -> Result<CrawlOutput> {
+        let url = self.build_url();
+        let backend = self
+            .create_backend()
+            .inspect_err(|e| eprintln!("❌ 리멤버 채용공고 수집 실패: {}", e))?;
+
+        println!("리멤버 채용공고 목록 수집 시작..",);
+        self.fetch_all_jobs(backend.as_ref(), &url, self.config.total_pages)
+            .inspect(|output| println!("\n✅ 리멤버 채용공고 {}개 수집 완료", output.jobs.len()))
+            .inspect_err(|e| eprintln!("❌ 리멤버 채용공고 수집 실패: {}", e))
+    }
+}
+
+impl DetailCrawler for RememberClient {
+    fn fetch_job_detail(
+        &self,
+        page: &dyn PageFetcher,
+        job: &Job,
+        config: &DetailCrawlConfig,
+    ) -> Result<Option<Job>> {
+        let document = self.open_detail(page, &job.url)?;
+
+        if !config.includes_any(&visible_text(&document)) {
+            return Ok(None);
+        }
+
+        let detail = JobDetail {
+            salary: self.extract_salary(&document),
+            description: self.extract_description(&document),
+            ..JobDetail::from_definitions(&document, &DETAIL_LABELS)
+        };
+
+        Ok(Some(detail.apply(job)))
+    }
+}
@@ -0,0 +1,330 @@
+use crate::Result;
+use crate::crawler::{
+    CrawlOutput, DEFAULT_MAX_PAGES, DetailCrawlConfig, DetailCrawler, DetailLabels, JobCrawler,
+    JobDetail, JobFieldExtractor, JobListPaginatedCrawler, ListFilter,
+};
+use crate::fetcher::{FetcherKind, PageFetcher};
+use crate::models::{Job, Salary};
+use crate::normalizers::parse_salary;
+use crate::pipeline::Crawler;
+use crate::utils::{encode_query_value, last_page_number, visible_text};
+use scraper::{Html, Selector};
+
+const DETAIL_LABELS: DetailLabels = DetailLabels {
+    deadline: Some("마감일"),
+    salary: None,
+    employment_type: None,
+    tech_stack: Some("업무 관련 기술/활동분야"),
+};
+
+#[derive(Debug, Clone)]
+pub struct RocketpunchCrawlConfig {
+    pub role: RocketpunchJobRole,
+    pub career: Option<RocketpunchCareer>,
+    /// "서울특별시", "경기도"처럼 로켓펀치 검색 조건의 지역 이름. None이면 전체 지역
+    pub location: Option<&'static str>,
+    /// 최대 수집 페이지 수. 첫 페이지에서 찾은 마지막 페이지가 더 작으면 그 페이지까지 수집.
+    /// None이면 찾은 마지막 페이지까지 모두 수집하고, 찾지 못하면 `DEFAULT_MAX_PAGES`까지 수집
+    pub total_pages: Option<usize>,
+    pub thread_count: usize,
+    pub exclude_keywords: Vec<&'static str>,
+    /// "서울 강남·서초", "판교"처럼 지역 조건 지정. 비어 있으면 전체 지역
+    pub locations: Vec<&'static str>,
+    pub fetcher: FetcherKind,
+}
+
+impl Default for RocketpunchCrawlConfig {
+    fn default() -> Self {
+        Self {
+            role: RocketpunchJobRole::Frontend,
+            career: None,
+            location: None,
+            total_pages: Some(DEFAULT_MAX_PAGES),
+            thread_count: 1,
+            exclude_keywords: Vec::new(),
+            locations: Vec::new(),
+            fetcher: FetcherKind::Chrome,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum RocketpunchJobRole {
+    Frontend,
+    Backend,
+    FullStack,
+    Android,
+    IOS,
+    DataEngineer,
+}
+
+impl RocketpunchJobRole {
+    pub fn to_name(&self) -> &'static str {
+        match self {
+            Self::Frontend => "프론트엔드",
+            Self::Backend => "백엔드",
+            Self::FullStack => "풀스택",
+            Self::Android => "안드로이드",
+            Self::IOS => "iOS",
+            Self::DataEngineer => "데이터엔지니어",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum RocketpunchCareer {
+    Newcomer,
+    Experienced,
+}
+
+impl RocketpunchCareer {
+    pub fn to_code(&self) -> u32 {
+        match self {
+            Self::Newcomer => 1,
+            Self::Experienced => 2,
+        }
+    }
+}
+
+pub struct RocketpunchClient {
+    base_url: String,
+    config: RocketpunchCrawlConfig,
+}
+
+impl RocketpunchClient {
+    pub fn new(config: RocketpunchCrawlConfig) -> Self {
+        Self {
+            base_url: "https://www.rocketpunch.com".to_string(),
+            config,
+        }
+    }
+
+    pub fn build_url(&self) -> String {
+        let mut url = format!("{}/jobs?job={}", self.base_url, self.config.role.to_name());
+        if let Some(career) = self.config.career {
+            url.push_str(&format!("&career_type={}", career.to_code()));
+        }
+        if let Some(location) = self.config.location {
+            url.push_str(&format!("&location={}", encode_query_value(location)));
+        }
+        url
+    }
+
+    /// 카드의 "연봉 / 경력 / 고용형태" 항목 중 조건에 맞는 첫 항목
+    fn stat_info(&self, fragment: &Html, matches: impl Fn(&str) -> bool) -> Option<String> {
+        let stat_selector = Selector::parse(r"div.job-stat-info").ok()?;
+        let text = fragment
+            .select(&stat_selector)
+            .next()?
+            .text()
+            .collect::<String>();
+        text.split('/')
+            .map(str::trim)
+            .find(|item| matches(item))
+            .map(str::to_string)
+    }
+
+    fn company_meta(&self, fragment: &Html, class: &str) -> Option<String> {
+        let selector = Selector::parse(&format!("div.company-meta span.{}", class)).ok()?;
+        let text = fragment
+            .select(&selector)
+            .next()?
+            .text()
+            .collect::<String>()
+            .trim()
+            .to_string();
+        Some(text).filter(|text| !text.is_empty())
+    }
+}
+
+impl Default for RocketpunchClient {
+    fn default() -> Self {
+        Self::new(RocketpunchCrawlConfig::default())
+    }
+}
+
+impl JobCrawler for RocketpunchClient {
+    fn site_name(&self) -> &str {
+        "로켓펀치"
+    }
+
+    fn fetcher_kind(&self) -> FetcherKind {
+        self.config.fetcher.clone()
+    }
+
+    fn list_filter(&self) -> ListFilter {
+        ListFilter::new(&self.config.exclude_keywords, &self.config.locations)
+    }
+
+    fn wait_for_list_page_load(&self, page: &dyn PageFetcher) -> Result<()> {
+        page.wait_for_element(r"div#search-results")
+    }
+
+    fn wait_for_detail_page_load(&self, page: &dyn PageFetcher) -> Result<()> {
+        page.wait_for_element(r"div.job-content")
+    }
+}
+
+impl JobListPaginatedCrawler for RocketpunchClient {
+    fn build_page_url(&self, base_url: &str, page: usize) -> String {
+        format!("{}&page={}", base_url, page)
+    }
+
+    /// 페이지 목록은 현재 구간과 함께 마지막 페이지 번호를 보여줌. "다음" 링크만 있으면 알 수 없음
+    fn parse_last_page(&self, html: &str) -> Option<usize> {
+        let document = Html::parse_document(html);
+        last_page_number(&document, r"div.pagination a.item")
+    }
+
+    /// 회사 카드마다 채용 중인 공고 목록이 붙어 있으므로 회사 정보를 공고마다 복사함
+    fn parse_html(&self, html: &str) -> Result<Vec<Job>> {
+        let document = Html::parse_document(html);
+        let company_selector = Selector::parse(r"div#search-results div.company.item").unwrap();
+        let job_selector = Selector::parse(r"div.company-jobs-detail div.job-detail").unwrap();
+
+        let jobs = document
+            .select(&company_selector)
+            .flat_map(|company_card| {
+                let company_fragment = Html::parse_fragment(&company_card.html());
+
+                let company = self.extract_company(&company_fragment).unwrap_or_default();
+                let location = self.extract_location(&company_fragment).unwrap_or_default();
+                let region = self.extract_region(&company_fragment);
+                let company_stage = self.company_meta(&company_fragment, "company-stage");
+                let investment_round = self.company_meta(&company_fragment, "investment-round");
+
+                company_card
+                    .select(&job_selector)
+                    .map(|job_detail| {
+                        let job_fragment = Html::parse_fragment(&job_detail.html());
+
+                        let title = self.extract_title(&job_fragment).unwrap_or_default();
+
+                        Job {
+                            title,
+                            company: company.clone(),
+                            experience_years: self
+                                .extract_experience_years(&job_fragment)
+                                .unwrap_or_default(),
+                            experience: self.extract_experience(&job_fragment),
+                            url: self.extract_url(&job_fragment).unwrap_or_default(),
+                            deadline: self.extract_deadline(&job_fragment).unwrap_or_default(),
+                            location: location.clone(),
+                            region: region.clone(),
+                            salary: self.extract_salary(&job_fragment),
+                            employment_type: self
+                                .stat_info(&job_fragment, |item| item.ends_with("직"))
+                                .unwrap_or_default(),
+                            company_stage: company_stage.clone(),
+                            investment_round: investment_round.clone(),
+                            ..Default::default()
+                        }
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        Ok(jobs)
+    }
+}
+
+impl JobFieldExtractor for RocketpunchClient {
+    fn extract_title(&self, fragment: &Html) -> Option<String> {
+        let title_selector = Selector::parse(r"a.job-title").ok()?;
+        let text = fragment
+            .select(&title_selector)
+            .next()?
+            .text()
+            .collect::<String>()
+            .trim()
+            .to_string();
+        Some(text)
+    }
+
+    fn extract_company(&self, fragment: &Html) -> Option<String> {
+        let company_selector = Selector::parse(r"div.company-name h4.name strong").ok()?;
+        let text = fragment
+            .select(&company_selector)
+            .next()?
+            .text()
+            .collect::<String>()
+            .trim()
+            .to_string();
+        Some(text)
+    }
+
+    fn extract_experience_years(&self, fragment: &Html) -> Option<String> {
+        self.stat_info(fragment, |item| {
+            item.contains("경력") || item.contains("신입")
+        })
+    }
+
+    fn extract_url(&self, fragment: &Html) -> Option<String> {
+        let title_selector = Selector::parse(r"a.job-title").ok()?;
+        let href = fragment.select(&title_selector).next()?.attr("href")?;
+        Some(format!("{}{}", self.base_url, href))
+    }
+
+    fn extract_deadline(&self, fragment: &Html) -> Option<String> {
+        let date_selector = Selector::parse(r"div.job-dates span.deadline").ok()?;
+        let text = fragment
+            .select(&date_selector)
+            .next()?
+            .text()
+            .collect::<String>()
+            .trim()
+            .to_string();
+        Some(text)
+    }
+
+    fn extract_location(&self, fragment: &Html) -> Option<String> {
+        self.company_meta(fragment, "company-location")
+    }
+
+    fn extract_salary(&self, fragment: &Html) -> Option<Salary> {
+        self.stat_info(fragment, |item| {
+            item.contains("만원") || item.contains("협의")
+        })
+        .and_then(|text| parse_salary(&text))
+    }
+}
+
+impl Crawler for RocketpunchClient {
+    fn start_crawl(&self) -> Result<CrawlOutput> {
+        let backend = self
+            .create_backend()
+            .inspect_err(|e| eprintln!("❌ 로켓펀치 채용공고 수집 실패: {}", e))?;
+
+        println!("로켓펀치 채용공고 목록 수집 시작..",);
+        self.fetch_all_jobs(
+            backend.as_ref(),
+            &self.build_url(),
+            self.config.total_pages,
+            self.config.thread_count,
+        )
+        .inspect(|output| println!("\n✅ 로켓펀치 {}개 채용공고 수집 완료", output.jobs.len()))
+        .inspect_err(|e| eprintln!("❌ 로켓펀치 채용공고 수집 실패: {}", e))
+    }
+}
+
+impl DetailCrawler for RocketpunchClient {
+    fn fetch_job_detail(
+        &self,
+        page: &dyn PageFetcher,
+        job: &Job,
+        config: &DetailCrawlConfig,
+    ) -> Result<Option<Job>> {
+        let document = self.open_detail(page, &job.url)?;
+
+        if !config.includes_any(&visible_text(&document)) {
+            return Ok(None);
+        }
+
+        let detail = JobDetail {
+            description: self.extract_description(&document),
+            ..JobDetail::from_definitions(&document, &DETAIL_LABELS)
+        };
+
+        Ok(Some(detail.apply(job)))
+    }
+}
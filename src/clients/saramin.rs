@@ -1,26 +1,71 @@
 use crate::Result;
-use crate::crawler::{JobCrawler, JobFieldExtractor, JobListPaginatedCrawler};
-use crate::models::Job;
+use crate::crawler::{
+    CrawlOutput, DEFAULT_MAX_PAGES, DetailCrawlConfig, DetailCrawler, DetailLabels, JobCrawler,
+    JobDetail, JobFieldExtractor, JobListPaginatedCrawler, ListFilter,
+};
+use crate::fetcher::{FetcherKind, PageFetcher};
+use crate::models::{Job, Salary};
+use crate::normalizers::find_salary;
 use crate::pipeline::Crawler;
-use headless_chrome::Tab;
+use crate::utils::{count_in, encode_query_value, last_page_number, visible_text};
 use scraper::{Html, Selector};
-use std::sync::Arc;
+
+/// 검색 결과 한 페이지에 나오는 공고 수 (사람인 기본값)
+const JOBS_PER_PAGE: usize = 40;
+
+const DETAIL_LABELS: DetailLabels = DetailLabels {
+    deadline: Some("마감일"),
+    salary: Some("급여"),
+    employment_type: Some("근무형태"),
+    tech_stack: None,
+};
 
 #[derive(Debug, Clone)]
 pub struct SaraminCrawlConfig {
     pub category: SaraminJobCategory,
-    pub total_pages: usize,
+    /// 신입, 경력처럼 지원 가능한 경력 구분. 비어 있으면 전체
+    pub careers: Vec<SaraminCareer>,
+    /// 경력 공고의 최소/최대 연차. 지정하면 경력 구분에 경력이 포함됨
+    pub min_years: Option<u8>,
+    pub max_years: Option<u8>,
+    /// 지원자의 최종 학력. 이 학력 이하를 요구하는 공고만 검색
+    pub education: Option<SaraminEducation>,
+    pub employment_types: Vec<SaraminEmploymentType>,
+    /// 사람인 지역 코드. 비어 있으면 전체 지역
+    pub regions: Vec<SaraminRegion>,
+    /// 만원 단위 최소 연봉. 사람인 검색 조건으로 전달됨
+    pub min_salary: Option<u32>,
+    pub company_types: Vec<SaraminCompanyType>,
+    /// None이면 사람인 기본 정렬(관련도순)
+    pub sort: Option<SaraminSort>,
+    /// 최대 수집 페이지 수. 첫 페이지에서 찾은 마지막 페이지가 더 작으면 그 페이지까지 수집.
+    /// None이면 찾은 마지막 페이지까지 모두 수집하고, 찾지 못하면 `DEFAULT_MAX_PAGES`까지 수집
+    pub total_pages: Option<usize>,
     pub thread_count: usize,
     pub exclude_keywords: Vec<&'static str>,
+    /// "서울 강남·서초", "판교"처럼 지역 조건 지정. 비어 있으면 전체 지역
+    pub locations: Vec<&'static str>,
+    pub fetcher: FetcherKind,
 }
 
 impl Default for SaraminCrawlConfig {
     fn default() -> Self {
         Self {
             category: SaraminJobCategory::Frontend,
-            total_pages: 1,
+            careers: Vec::new(),
+            min_years: None,
+            max_years: None,
+            education: None,
+            employment_types: Vec::new(),
+            regions: Vec::new(),
+            min_salary: None,
+            company_types: Vec::new(),
+            sort: None,
+            total_pages: Some(DEFAULT_MAX_PAGES),
             thread_count: 1,
             exclude_keywords: Vec::new(),
+            locations: Vec::new(),
+            fetcher: FetcherKind::Chrome,
         }
     }
 }
@@ -28,16 +73,178 @@ impl Default for SaraminCrawlConfig {
 #[derive(Debug, Clone, Copy)]
 pub enum SaraminJobCategory {
     Frontend,
+    Backend,
+    FullStack,
+    Android,
+    IOS,
+    DataEngineer,
+    /// "React 개발자"처럼 검색창에 입력하는 임의의 검색어
+    Keyword(&'static str),
 }
 
 impl SaraminJobCategory {
     pub fn to_word(&self) -> &str {
         match self {
             Self::Frontend => "프론트엔드",
+            Self::Backend => "백엔드",
+            Self::FullStack => "풀스택",
+            Self::Android => "안드로이드",
+            Self::IOS => "iOS",
+            Self::DataEngineer => "데이터 엔지니어",
+            Self::Keyword(word) => word,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum SaraminCareer {
+    Newcomer,
+    Experienced,
+    Any,
+}
+
+impl SaraminCareer {
+    pub fn to_code(&self) -> u32 {
+        match self {
+            Self::Newcomer => 1,
+            Self::Experienced => 2,
+            Self::Any => 3,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum SaraminEducation {
+    HighSchool,
+    College,
+    University,
+    Master,
+    Doctor,
+}
+
+impl SaraminEducation {
+    pub fn to_code(&self) -> u32 {
+        match self {
+            Self::HighSchool => 6,
+            Self::College => 7,
+            Self::University => 8,
+            Self::Master => 9,
+            Self::Doctor => 10,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum SaraminEmploymentType {
+    Permanent,
+    Contract,
+    MilitaryService,
+    Intern,
+    PartTime,
+    Dispatch,
+    Freelancer,
+}
+
+impl SaraminEmploymentType {
+    pub fn to_code(&self) -> u32 {
+        match self {
+            Self::Permanent => 1,
+            Self::Contract => 2,
+            Self::MilitaryService => 3,
+            Self::Intern => 4,
+            Self::PartTime => 5,
+            Self::Dispatch => 6,
+            Self::Freelancer => 9,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum SaraminRegion {
+    Seoul,
+    Gyeonggi,
+    Incheon,
+    Busan,
+    Daegu,
+    Gwangju,
+    Daejeon,
+    Ulsan,
+    Sejong,
+    /// 101050(서울 강남구)처럼 사람인 검색 페이지의 지역 코드
+    Custom(u32),
+}
+
+impl SaraminRegion {
+    pub fn to_code(&self) -> u32 {
+        match self {
+            Self::Seoul => 101000,
+            Self::Gyeonggi => 102000,
+            Self::Gwangju => 103000,
+            Self::Daegu => 104000,
+            Self::Daejeon => 105000,
+            Self::Busan => 106000,
+            Self::Ulsan => 107000,
+            Self::Incheon => 108000,
+            Self::Sejong => 118000,
+            Self::Custom(code) => *code,
+        }
+    }
+
+    /// 1000 단위로 끝나는 코드는 시·도 전체, 나머지는 시·군·구
+    fn is_sido(&self) -> bool {
+        self.to_code().is_multiple_of(1000)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum SaraminCompanyType {
+    Large,
+    MidSized,
+    Public,
+    Foreign,
+    Listed,
+}
+
+impl SaraminCompanyType {
+    pub fn to_code(&self) -> u32 {
+        match self {
+            Self::Large => 1,
+            Self::MidSized => 2,
+            Self::Public => 3,
+            Self::Foreign => 4,
+            Self::Listed => 5,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum SaraminSort {
+    Relevance,
+    Latest,
+    Deadline,
+    Updated,
+}
+
+impl SaraminSort {
+    pub fn to_code(&self) -> &'static str {
+        match self {
+            Self::Relevance => "RL",
+            Self::Latest => "DA",
+            Self::Deadline => "EA",
+            Self::Updated => "EU",
         }
     }
 }
 
+/// 여러 값을 받는 검색 조건은 쉼표로 구분해서 전달
+fn join_codes(codes: impl IntoIterator<Item = u32>) -> String {
+    codes
+        .into_iter()
+        .map(|code| code.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
 pub struct SaraminClient {
     base_url: String,
     config: SaraminCrawlConfig,
@@ -50,16 +257,91 @@ impl SaraminClient {
             config,
         }
     }
+
+    /// 검색 조건을 담은 목록 URL. 검색어는 "C#", "C++"처럼 특수문자가 있어도 그대로 검색되도록 인코딩
+    pub fn build_url(&self) -> String {
+        let config = &self.config;
+        let mut url = format!(
+            "{}/zf_user/search/recruit?searchword={}",
+            self.base_url,
+            encode_query_value(config.category.to_word())
+        );
+
+        let mut careers: Vec<u32> = config.careers.iter().map(|c| c.to_code()).collect();
+        let experienced = SaraminCareer::Experienced.to_code();
+        if (config.min_years.is_some() || config.max_years.is_some())
+            && !careers.contains(&experienced)
+        {
+            careers.push(experienced);
+        }
+        if !careers.is_empty() {
+            url.push_str(&format!("&exp_cd={}", join_codes(careers)));
+        }
+        if let Some(min_years) = config.min_years {
+            url.push_str(&format!("&exp_min={}", min_years));
+        }
+        if let Some(max_years) = config.max_years {
+            url.push_str(&format!("&exp_max={}", max_years));
+        }
+        if let Some(education) = config.education {
+            url.push_str(&format!("&edu_max={}", education.to_code()));
+        }
+        if !config.employment_types.is_empty() {
+            let codes = config.employment_types.iter().map(|t| t.to_code());
+            url.push_str(&format!("&job_type={}", join_codes(codes)));
+        }
+
+        let (sido, sigungu): (Vec<&SaraminRegion>, Vec<_>) =
+            config.regions.iter().partition(|region| region.is_sido());
+        if !sido.is_empty() {
+            let codes = sido.iter().map(|region| region.to_code());
+            url.push_str(&format!("&loc_mcd={}", join_codes(codes)));
+        }
+        if !sigungu.is_empty() {
+            let codes = sigungu.iter().map(|region| region.to_code());
+            url.push_str(&format!("&loc_cd={}", join_codes(codes)));
+        }
+
+        if let Some(min_salary) = config.min_salary {
+            url.push_str(&format!("&sal_min={}", min_salary));
+        }
+        if !config.company_types.is_empty() {
+            let codes = config.company_types.iter().map(|t| t.to_code());
+            url.push_str(&format!("&company_cd={}", join_codes(codes)));
+        }
+        if let Some(sort) = config.sort {
+            url.push_str(&format!("&sort={}", sort.to_code()));
+        }
+        url
+    }
+
+    /// 공고 본문은 relay/view-detail iframe 안에 있음
+    fn extract_content_url(&self, document: &Html) -> Option<String> {
+        let iframe_selector = Selector::parse(r"iframe#iframe_content_0").ok()?;
+        let src = document.select(&iframe_selector).next()?.attr("src")?;
+        Some(format!("{}{}", self.base_url, src))
+    }
 }
 
 impl JobCrawler for SaraminClient {
-    fn wait_for_list_page_load(&self, tab: &Arc<Tab>) -> Result<()> {
-        tab.wait_for_element(r"#recruit_info_list")?;
-        Ok(())
+    fn site_name(&self) -> &str {
+        "사람인"
     }
 
-    fn wait_for_detail_page_load(&self, _tab: &Arc<Tab>) -> Result<()> {
-        Ok(())
+    fn fetcher_kind(&self) -> FetcherKind {
+        self.config.fetcher.clone()
+    }
+
+    fn list_filter(&self) -> ListFilter {
+        ListFilter::new(&self.config.exclude_keywords, &self.config.locations)
+    }
+
+    fn wait_for_list_page_load(&self, page: &dyn PageFetcher) -> Result<()> {
+        page.wait_for_element(r"#recruit_info_list")
+    }
+
+    fn wait_for_detail_page_load(&self, page: &dyn PageFetcher) -> Result<()> {
+        page.wait_for_element(r".jv_summary")
     }
 }
 
@@ -68,45 +350,49 @@ impl JobListPaginatedCrawler for SaraminClient {
         format!("{}&recruitPage={}", base_url, page)
     }
 
+    /// "총 N건" 검색 결과 수로 계산하고, 없으면 페이지 목록에서 찾음
+    fn parse_last_page(&self, html: &str) -> Option<usize> {
+        let document = Html::parse_document(html);
+        count_in(&document, r"span.cnt_result")
+            .map(|count| count.div_ceil(JOBS_PER_PAGE).max(1))
+            .or_else(|| last_page_number(&document, r"div.pagination a"))
+    }
+
     fn parse_html(&self, html: &str) -> Result<Vec<Job>> {
         let document = Html::parse_document(html);
         let job_card_selector = Selector::parse(r"div.item_recruit").unwrap();
 
         let jobs = document
             .select(&job_card_selector)
-            .filter_map(|card| {
+            .map(|card| {
                 let card_html = card.html();
                 let card_fragment = Html::parse_fragment(&card_html);
 
                 let title = self.extract_title(&card_fragment).unwrap_or_default();
-                let should_exclude = self
-                    .config
-                    .exclude_keywords
-                    .iter()
-                    .any(|key| title.to_lowercase().contains(&key.to_lowercase()));
-
-                if should_exclude {
-                    return None;
-                }
 
                 let company = self.extract_company(&card_fragment).unwrap_or_default();
                 let experience_years = self
                     .extract_experience_years(&card_fragment)
                     .unwrap_or_default();
+                let experience = self.extract_experience(&card_fragment);
                 let url = self.extract_url(&card_fragment).unwrap_or_default();
                 let deadline = self.extract_deadline(&card_fragment).unwrap_or_default();
                 let location = self.extract_location(&card_fragment).unwrap_or_default();
+                let salary = self.extract_salary(&card_fragment);
+                let region = self.extract_region(&card_fragment);
 
-                Some(Job {
+                Job {
                     title,
                     company,
                     experience_years,
+                    experience,
                     url,
                     deadline,
                     location,
-                    rating: None,
-                    review_count: None,
-                })
+                    region,
+                    salary,
+                    ..Default::default()
+                }
             })
             .collect();
 
@@ -189,31 +475,67 @@ impl JobFieldExtractor for SaraminClient {
             .join(" ");
         Some(text)
     }
+
+    fn extract_salary(&self, fragment: &Html) -> Option<Salary> {
+        let job_condition_selector = Selector::parse(r"div.job_condition").ok()?;
+        let span_selector = Selector::parse("span").ok()?;
+
+        let condition = fragment.select(&job_condition_selector).next()?;
+        find_salary(
+            condition
+                .select(&span_selector)
+                .map(|span| span.text().collect::<String>()),
+        )
+    }
 }
 
 impl Crawler for SaraminClient {
-    fn start_crawl(&self) -> Result<Vec<Job>> {
-        let browser = self
-            .create_browser()
+    fn start_crawl(&self) -> Result<CrawlOutput> {
+        let backend = self
+            .create_backend()
             .inspect_err(|e| eprintln!("❌ 사람인 채용공고 수집 실패: {}", e))?;
 
-        let refined_url = format!(
-            "{}/zf_user/search/recruit?searchword={}",
-            self.base_url,
-            self.config.category.to_word()
-        );
-
         println!("사람인 채용공고 목록 수집 시작..",);
         let jobs = self
             .fetch_all_jobs(
-                &browser,
-                &refined_url,
+                backend.as_ref(),
+                &self.build_url(),
                 self.config.total_pages,
                 self.config.thread_count,
             )
-            .inspect(|jobs| println!("\n✅ 사람인 {}개 채용공고 수집 완료", jobs.len()))
+            .inspect(|output| println!("\n✅ 사람인 {}개 채용공고 수집 완료", output.jobs.len()))
             .inspect_err(|e| eprintln!("❌ 사람인 채용공고 수집 실패: {}", e))?;
 
         Ok(jobs)
     }
 }
+
+impl DetailCrawler for SaraminClient {
+    fn fetch_job_detail(
+        &self,
+        page: &dyn PageFetcher,
+        job: &Job,
+        config: &DetailCrawlConfig,
+    ) -> Result<Option<Job>> {
+        let document = self.open_detail(page, &job.url)?;
+        let content = match self.extract_content_url(&document) {
+            Some(content_url) => {
+                page.navigate_to(&content_url)?;
+                Html::parse_document(&page.get_content()?)
+            }
+            None => document.clone(),
+        };
+
+        let text = format!("{} {}", visible_text(&document), visible_text(&content));
+        if !config.includes_any(&text) {
+            return Ok(None);
+        }
+
+        let detail = JobDetail {
+            description: self.extract_description(&content),
+            ..JobDetail::from_definitions(&document, &DETAIL_LABELS)
+        };
+
+        Ok(Some(detail.apply(job)))
+    }
+}
@@ -1,22 +1,40 @@
 use crate::crawler::{
-    DetailCrawlConfig, DetailCrawler, JobCrawler, JobFieldExtractor, JobListInfiniteScrollCrawler,
+    CrawlOutput, DetailCrawlConfig, DetailCrawler, JobCrawler, JobDetail, JobFieldExtractor,
+    JobListInfiniteScrollCrawler, ListFilter,
 };
+use crate::fetcher::{FetcherKind, PageFetcher};
+use crate::health::PageStat;
+use crate::models::Salary;
+use crate::normalizers::{merge_tech_stack, normalize_tech, parse_salary};
+use crate::normalizers::{parse_experience, parse_region};
 use crate::pipeline::Crawler;
-use crate::{Job, Result};
-use headless_chrome::Tab;
-use scraper::{Html, Selector};
-use std::sync::Arc;
+use crate::{Error, Job, Result};
+use scraper::{ElementRef, Html, Selector};
+use serde::Deserialize;
+use std::sync::LazyLock;
 use std::time::Duration;
 
+/// 목록 API 한 번에 받아오는 공고 수
+const API_PAGE_SIZE: usize = 20;
+
 #[derive(Debug, Clone)]
 pub struct WantedCrawlConfig {
     pub category: WantedJobCategory,
     pub subcategory: WantedJobSubcategory,
+    pub sort: WantedJobSort,
+    /// 원티드 검색 조건의 지역. 비어 있으면 전체 지역
+    pub location_tags: Vec<WantedLocation>,
+    /// 원티드 기술 태그 ID (예: React 1541). 비어 있으면 전체
+    pub skill_tags: Vec<u32>,
     pub total_pages: usize,
     pub min_years: u8,
     pub max_years: u8,
     pub thread_count: usize,
     pub exclude_keywords: Vec<&'static str>,
+    /// "서울 강남·서초", "판교"처럼 지역 조건 지정. 비어 있으면 전체 지역
+    pub locations: Vec<&'static str>,
+    pub fetcher: FetcherKind,
+    pub mode: WantedCrawlMode,
 }
 
 impl Default for WantedCrawlConfig {
@@ -24,45 +42,354 @@ impl Default for WantedCrawlConfig {
         Self {
             category: WantedJobCategory::Development,
             subcategory: WantedJobSubcategory::Frontend,
+            sort: WantedJobSort::Recommend,
+            location_tags: Vec::new(),
+            skill_tags: Vec::new(),
             total_pages: 1,
             min_years: 0,
             max_years: 5,
             thread_count: 8,
             exclude_keywords: Vec::new(),
+            locations: Vec::new(),
+            fetcher: FetcherKind::Chrome,
+            mode: WantedCrawlMode::Html,
         }
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum WantedCrawlMode {
+    /// 목록 페이지를 스크롤하며 JobCard를 파싱
+    #[default]
+    Html,
+    /// 원티드 웹이 사용하는 목록 API를 직접 호출해 JSON을 파싱.
+    /// 카드에 없는 회사 ID, 마감일, 기술 태그까지 수집함.
+    /// HTTP 전용이라 `fetcher`가 `FetcherKind::Chrome`이면 `Error::Config` 오류
+    Api,
+}
+
+/// 직군 (`/wdlist/{직군}/{직무}`의 앞부분)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum WantedJobCategory {
     Development,
+    Business,
+    Marketing,
+    Design,
+    Sales,
+    CustomerService,
+    Media,
+    Engineering,
+    HR,
+    Finance,
+    Game,
+    /// 열거형에 없는 직군 코드
+    Custom(u32),
 }
 
 impl WantedJobCategory {
-    pub fn to_code(&self) -> u32 {
+    const NAMED: [Self; 11] = [
+        Self::Development,
+        Self::Business,
+        Self::Marketing,
+        Self::Design,
+        Self::Sales,
+        Self::CustomerService,
+        Self::Media,
+        Self::Engineering,
+        Self::HR,
+        Self::Finance,
+        Self::Game,
+    ];
+
+    /// 직무표(`data/wanted_categories.txt`)의 이름. 코드는 직무표에만 적음
+    fn table_name(&self) -> &'static str {
+        match self {
+            Self::Development => "개발",
+            Self::Business => "경영·비즈니스",
+            Self::Marketing => "마케팅·광고",
+            Self::Design => "디자인",
+            Self::Sales => "영업",
+            Self::CustomerService => "고객서비스·리테일",
+            Self::Media => "미디어",
+            Self::Engineering => "엔지니어링·설계",
+            Self::HR => "HR",
+            Self::Finance => "금융",
+            Self::Game => "게임 제작",
+            Self::Custom(_) => "",
+        }
+    }
+
+    /// 직무표에서 찾은 직군 코드. 직무표에 이름이 없으면 None
+    pub fn to_code(&self) -> Option<u32> {
         match self {
-            Self::Development => 518,
+            Self::Custom(code) => Some(*code),
+            named => find_category(named.table_name(), |entry| entry.job_code.is_none())
+                .map(|entry| entry.category_code),
         }
     }
+
+    pub fn from_code(code: u32) -> Self {
+        Self::NAMED
+            .into_iter()
+            .find(|category| category.to_code() == Some(code))
+            .unwrap_or(Self::Custom(code))
+    }
+
+    /// "개발", "design" 같은 이름/별칭이나 "518" 같은 숫자 코드로 직군을 찾음
+    pub fn find(text: &str) -> Option<Self> {
+        let code = match text.trim().parse() {
+            Ok(code) => code,
+            Err(_) => find_category(text, |entry| entry.job_code.is_none())?.category_code,
+        };
+        Some(Self::from_code(code))
+    }
 }
 
-#[derive(Debug, Clone, Copy)]
+/// 직무 (`/wdlist/{직군}/{직무}`의 뒷부분)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum WantedJobSubcategory {
     Frontend,
     Backend,
     Web,
     Android,
     IOS,
+    SoftwareEngineer,
+    CrossPlatform,
+    Java,
+    Python,
+    NodeJs,
+    DataEngineer,
+    DataScientist,
+    MachineLearning,
+    BigData,
+    DevOps,
+    SystemNetwork,
+    Security,
+    DBA,
+    Embedded,
+    QA,
+    WebPublisher,
+    ProductManager,
+    DevelopmentManager,
+    UxDesigner,
+    UiDesigner,
+    ServicePlanner,
+    ProductOwner,
+    /// 열거형에 없는 직무 코드
+    Custom(u32),
 }
 
 impl WantedJobSubcategory {
-    pub fn to_code(&self) -> u32 {
+    const NAMED: [Self; 27] = [
+        Self::Frontend,
+        Self::Backend,
+        Self::Web,
+        Self::Android,
+        Self::IOS,
+        Self::SoftwareEngineer,
+        Self::CrossPlatform,
+        Self::Java,
+        Self::Python,
+        Self::NodeJs,
+        Self::DataEngineer,
+        Self::DataScientist,
+        Self::MachineLearning,
+        Self::BigData,
+        Self::DevOps,
+        Self::SystemNetwork,
+        Self::Security,
+        Self::DBA,
+        Self::Embedded,
+        Self::QA,
+        Self::WebPublisher,
+        Self::ProductManager,
+        Self::DevelopmentManager,
+        Self::UxDesigner,
+        Self::UiDesigner,
+        Self::ServicePlanner,
+        Self::ProductOwner,
+    ];
+
+    /// 직무표(`data/wanted_categories.txt`)의 이름. 코드와 직군은 직무표에만 적음
+    fn table_name(&self) -> &'static str {
+        match self {
+            Self::Frontend => "프론트엔드 개발자",
+            Self::Backend => "서버 개발자",
+            Self::Web => "웹 개발자",
+            Self::Android => "안드로이드 개발자",
+            Self::IOS => "iOS 개발자",
+            Self::SoftwareEngineer => "소프트웨어 엔지니어",
+            Self::CrossPlatform => "크로스플랫폼 앱 개발자",
+            Self::Java => "자바 개발자",
+            Self::Python => "파이썬 개발자",
+            Self::NodeJs => "Node.js 개발자",
+            Self::DataEngineer => "데이터 엔지니어",
+            Self::DataScientist => "데이터 사이언티스트",
+            Self::MachineLearning => "머신러닝 엔지니어",
+            Self::BigData => "빅데이터 엔지니어",
+            Self::DevOps => "DevOps / 시스템 관리자",
+            Self::SystemNetwork => "시스템,네트워크 관리자",
+            Self::Security => "보안 엔지니어",
+            Self::DBA => "DBA",
+            Self::Embedded => "임베디드 개발자",
+            Self::QA => "QA,테스트 엔지니어",
+            Self::WebPublisher => "웹 퍼블리셔",
+            Self::ProductManager => "프로덕트 매니저",
+            Self::DevelopmentManager => "개발 매니저",
+            Self::UxDesigner => "UX 디자이너",
+            Self::UiDesigner => "UI,GUI 디자이너",
+            Self::ServicePlanner => "서비스 기획자",
+            Self::ProductOwner => "PM·PO",
+            Self::Custom(_) => "",
+        }
+    }
+
+    /// 직무표에서 찾은 직무 코드. 직무표에 이름이 없으면 None
+    pub fn to_code(&self) -> Option<u32> {
+        match self {
+            Self::Custom(code) => Some(*code),
+            named => find_category(named.table_name(), |entry| entry.job_code.is_some())
+                .and_then(|entry| entry.job_code),
+        }
+    }
+
+    pub fn from_code(code: u32) -> Self {
+        Self::NAMED
+            .into_iter()
+            .find(|subcategory| subcategory.to_code() == Some(code))
+            .unwrap_or(Self::Custom(code))
+    }
+
+    /// "프론트엔드 개발자", "devops" 같은 이름/별칭이나 "669" 같은 숫자 코드로 직무를 찾음
+    pub fn find(text: &str) -> Option<Self> {
+        let code = match text.trim().parse() {
+            Ok(code) => code,
+            Err(_) => find_category(text, |entry| entry.job_code.is_some())?.job_code?,
+        };
+        Some(Self::from_code(code))
+    }
+
+    /// 직무가 속한 직군. 직무표에 없는 코드면 None
+    pub fn category(&self) -> Option<WantedJobCategory> {
+        let code = self.to_code()?;
+        CATEGORY_TABLE
+            .iter()
+            .find(|entry| entry.job_code == Some(code))
+            .map(|entry| WantedJobCategory::from_code(entry.category_code))
+    }
+}
+
+const WANTED_CATEGORIES: &str = include_str!("../../data/wanted_categories.txt");
+
+struct CategoryEntry {
+    category_code: u32,
+    job_code: Option<u32>,
+    names: Vec<String>,
+}
+
+static CATEGORY_TABLE: LazyLock<Vec<CategoryEntry>> = LazyLock::new(|| {
+    WANTED_CATEGORIES
+        .lines()
+        .filter(|line| !line.starts_with('#') && !line.trim().is_empty())
+        .filter_map(|line| {
+            let mut columns = line.split('\t');
+            let category_code = columns.next()?.trim().parse().ok()?;
+            let job_code = columns.next()?.trim().parse().ok();
+            let name = columns.next()?.trim();
+            let aliases = columns.next().unwrap_or_default().split(',');
+            let names = std::iter::once(name)
+                .chain(aliases)
+                .map(|name| name.trim().to_lowercase())
+                .filter(|name| !name.is_empty())
+                .collect();
+            Some(CategoryEntry {
+                category_code,
+                job_code,
+                names,
+            })
+        })
+        .collect()
+});
+
+fn find_category(
+    text: &str,
+    kind: impl Fn(&CategoryEntry) -> bool,
+) -> Option<&'static CategoryEntry> {
+    let text = text.trim().to_lowercase();
+    CATEGORY_TABLE
+        .iter()
+        .filter(|entry| kind(entry))
+        .find(|entry| entry.names.contains(&text))
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum WantedJobSort {
+    /// 응답률순 (원티드 기본값)
+    #[default]
+    Recommend,
+    /// 최신순
+    Latest,
+    /// 인기순
+    Popular,
+    /// 보상금순
+    Reward,
+}
+
+impl WantedJobSort {
+    pub fn to_code(&self) -> &'static str {
+        match self {
+            Self::Recommend => "job.recommend_order",
+            Self::Latest => "job.latest_order",
+            Self::Popular => "job.popularity_order",
+            Self::Reward => "job.compensation_order",
+        }
+    }
+}
+
+/// 원티드 검색 조건의 지역. 구 단위 등 열거형에 없는 지역은 `Custom("seoul.gangnam-gu")`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WantedLocation {
+    Seoul,
+    Busan,
+    Daegu,
+    Incheon,
+    Gwangju,
+    Daejeon,
+    Ulsan,
+    Sejong,
+    Gyeonggi,
+    Gangwon,
+    Chungbuk,
+    Chungnam,
+    Jeonbuk,
+    Jeonnam,
+    Gyeongbuk,
+    Gyeongnam,
+    Jeju,
+    Custom(&'static str),
+}
+
+impl WantedLocation {
+    pub fn to_code(&self) -> &'static str {
         match self {
-            Self::Frontend => 669,
-            Self::Backend => 872,
-            Self::Web => 873,
-            Self::Android => 677,
-            Self::IOS => 678,
+            Self::Seoul => "seoul.all",
+            Self::Busan => "busan.all",
+            Self::Daegu => "daegu.all",
+            Self::Incheon => "incheon.all",
+            Self::Gwangju => "gwangju.all",
+            Self::Daejeon => "daejeon.all",
+            Self::Ulsan => "ulsan.all",
+            Self::Sejong => "sejong.all",
+            Self::Gyeonggi => "gyeonggi.all",
+            Self::Gangwon => "gangwon.all",
+            Self::Chungbuk => "chungbuk.all",
+            Self::Chungnam => "chungnam.all",
+            Self::Jeonbuk => "jeonbuk.all",
+            Self::Jeonnam => "jeonnam.all",
+            Self::Gyeongbuk => "gyeongbuk.all",
+            Self::Gyeongnam => "gyeongnam.all",
+            Self::Jeju => "jeju.all",
+            Self::Custom(code) => code,
         }
     }
 }
@@ -80,27 +407,254 @@ impl WantedClient {
         }
     }
 
-    fn build_url(&self) -> String {
-        format!(
-            "{}/wdlist/{}/{}?country=kr&job_sort=job.recommend_order&years={}&years={}&locations=all",
-            self.base_url,
-            self.config.category.to_code(),
-            self.config.subcategory.to_code(),
+    /// 테스트 서버 등 다른 주소로 요청을 보낼 때 사용
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    /// 목록 페이지와 목록 API가 함께 쓰는 검색 조건
+    fn build_query(&self) -> String {
+        let mut query = format!(
+            "country=kr&job_sort={}&years={}&years={}",
+            self.config.sort.to_code(),
             self.config.min_years,
             self.config.max_years
-        )
+        );
+        if self.config.location_tags.is_empty() {
+            query.push_str("&locations=all");
+        }
+        for location in &self.config.location_tags {
+            query.push_str(&format!("&locations={}", location.to_code()));
+        }
+        for skill_tag in &self.config.skill_tags {
+            query.push_str(&format!("&skill_tags={}", skill_tag));
+        }
+        query
+    }
+
+    /// 직군·직무 코드. 직무표에 없는 직군·직무이거나 직무표에 있는 직무가 다른 직군에
+    /// 지정되면(`/518/597`) 오류
+    fn category_codes(&self) -> Result<(u32, u32)> {
+        let missing = |name: &str| {
+            Error::config(
+                self.site_name(),
+                format!("data/wanted_categories.txt에 없는 직군/직무: {}", name),
+            )
+        };
+        let category = self
+            .config
+            .category
+            .to_code()
+            .ok_or_else(|| missing(self.config.category.table_name()))?;
+        let subcategory = self
+            .config
+            .subcategory
+            .to_code()
+            .ok_or_else(|| missing(self.config.subcategory.table_name()))?;
+
+        match self
+            .config
+            .subcategory
+            .category()
+            .and_then(|expected| expected.to_code())
+        {
+            Some(expected) if expected != category => Err(Error::config(
+                self.site_name(),
+                format!(
+                    "직무 {}는 직군 {}에 속함 (지정한 직군: {})",
+                    subcategory, expected, category
+                ),
+            )),
+            _ => Ok((category, subcategory)),
+        }
+    }
+
+    pub fn build_url(&self) -> Result<String> {
+        let (category, subcategory) = self.category_codes()?;
+        Ok(format!(
+            "{}/wdlist/{}/{}?{}",
+            self.base_url,
+            category,
+            subcategory,
+            self.build_query()
+        ))
+    }
+
+    fn build_api_url(&self, offset: usize) -> Result<String> {
+        let (category, subcategory) = self.category_codes()?;
+        Ok(format!(
+            "{}/api/chaos/navigation/v1/results?job_group_id={}&job_ids={}&{}&limit={}&offset={}",
+            self.base_url,
+            category,
+            subcategory,
+            self.build_query(),
+            API_PAGE_SIZE,
+            offset
+        ))
+    }
+
+    /// 목록 API 응답을 `Job`으로 변환하고 다음 페이지가 있는지 함께 돌려줌.
+    /// 제외 키워드와 지역 조건은 `list_filter`로 따로 적용
+    pub fn parse_api_response(&self, url: &str, body: &str) -> Result<(Vec<Job>, bool)> {
+        let response: ApiResponse =
+            serde_json::from_str(body).map_err(|e| Error::parse(self.site_name(), url, e))?;
+        let has_next = response.links.next.is_some() && !response.data.is_empty();
+
+        let jobs = response
+            .data
+            .into_iter()
+            .map(|item| self.api_job(item))
+            .collect();
+
+        Ok((jobs, has_next))
+    }
+
+    fn api_job(&self, item: ApiJob) -> Job {
+        let experience_years = match (item.annual_from, item.annual_to) {
+            (Some(0), Some(0)) => "신입".to_string(),
+            (Some(0), Some(to)) if to < 100 => format!("신입-경력 {}년", to),
+            (Some(from), Some(to)) if from > 0 && to < 100 => format!("경력 {}-{}년", from, to),
+            (Some(from), _) if from > 0 => format!("경력 {}년 이상", from),
+            _ => "경력무관".to_string(),
+        };
+        let location = [item.address.location, item.address.district]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        let mut tech_stack = Vec::new();
+        merge_tech_stack(
+            &mut tech_stack,
+            item.skill_tags.into_iter().map(|tag| {
+                normalize_tech(&tag.title)
+                    .map(str::to_string)
+                    .unwrap_or(tag.title)
+            }),
+        );
+
+        Job {
+            title: item.position,
+            company: item.company.name,
+            company_id: Some(item.company.id.to_string()),
+            experience: parse_experience(&experience_years),
+            experience_years,
+            deadline: item.due_time.unwrap_or_else(|| "상시채용".to_string()),
+            region: parse_region(&location),
+            location,
+            tech_stack,
+            url: format!("{}/wd/{}", self.base_url, item.id),
+            ..Default::default()
+        }
     }
+
+    /// 실패한 페이지는 `pages`에 오류로 남기고 다음 offset으로 넘어감
+    fn fetch_api_jobs(&self) -> Result<CrawlOutput> {
+        // 브라우저로 JSON을 열면 HTML로 감싸지고 요청을 가로채는 기능도 없으므로 HTTP로만 수집
+        if matches!(self.fetcher_kind(), FetcherKind::Chrome) {
+            return Err(Error::config(
+                self.site_name(),
+                "API 모드는 FetcherKind::Http로만 수집할 수 있음",
+            ));
+        }
+        let backend = self.create_backend()?;
+        let page = backend.new_page()?;
+        let filter = self.list_filter();
+        let mut output = CrawlOutput::default();
+
+        for current_page in 1..=self.config.total_pages {
+            let url = self.build_api_url((current_page - 1) * API_PAGE_SIZE)?;
+            let result = page
+                .navigate_to(&url)
+                .and_then(|_| page.get_content())
+                .and_then(|body| self.parse_api_response(&url, &body));
+            output.pages.push(PageStat::new(
+                &url,
+                current_page,
+                result.as_ref().map(|(cards, _)| cards.len()),
+            ));
+
+            match result {
+                Ok((cards, has_next)) => {
+                    output.jobs.extend(filter.apply(cards));
+                    println!("페이지 {}: 총 {}개 수집", current_page, output.jobs.len());
+
+                    if !has_next {
+                        break;
+                    }
+                }
+                Err(e) => eprintln!("페이지 {}: 처리 실패: {}", current_page, e),
+            }
+            std::thread::sleep(Duration::from_millis(500));
+        }
+
+        Ok(output)
+    }
+}
+
+#[derive(Deserialize)]
+struct ApiResponse {
+    data: Vec<ApiJob>,
+    #[serde(default)]
+    links: ApiLinks,
+}
+
+#[derive(Deserialize, Default)]
+struct ApiLinks {
+    next: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct ApiJob {
+    id: u64,
+    position: String,
+    company: ApiCompany,
+    #[serde(default)]
+    address: ApiAddress,
+    due_time: Option<String>,
+    annual_from: Option<u8>,
+    annual_to: Option<u8>,
+    #[serde(default)]
+    skill_tags: Vec<ApiTag>,
+}
+
+#[derive(Deserialize)]
+struct ApiCompany {
+    id: u64,
+    name: String,
+}
+
+#[derive(Deserialize, Default)]
+struct ApiAddress {
+    location: Option<String>,
+    district: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct ApiTag {
+    title: String,
 }
 
 impl JobCrawler for WantedClient {
-    fn wait_for_list_page_load(&self, tab: &Arc<Tab>) -> Result<()> {
-        tab.wait_for_element(r#"div[class*="JobCard_JobCard__body__"]"#)?;
-        Ok(())
+    fn site_name(&self) -> &str {
+        "원티드"
     }
 
-    fn wait_for_detail_page_load(&self, tab: &Arc<Tab>) -> Result<()> {
-        tab.wait_for_element("body")?;
-        Ok(())
+    fn fetcher_kind(&self) -> FetcherKind {
+        self.config.fetcher.clone()
+    }
+
+    fn list_filter(&self) -> ListFilter {
+        ListFilter::new(&self.config.exclude_keywords, &self.config.locations)
+    }
+
+    fn wait_for_list_page_load(&self, page: &dyn PageFetcher) -> Result<()> {
+        page.wait_for_element(r#"div[class*="JobCard_JobCard__body__"]"#)
+    }
+
+    fn wait_for_detail_page_load(&self, page: &dyn PageFetcher) -> Result<()> {
+        page.wait_for_element("body")
     }
 }
 
@@ -112,23 +666,16 @@ impl JobListInfiniteScrollCrawler for WantedClient {
 
         let jobs = document
             .select(&body_selector)
-            .filter_map(|body_element| {
+            .map(|body_element| {
                 let body_doc = Html::parse_fragment(&body_element.html());
 
                 let title = self.extract_title(&body_doc).unwrap_or_default();
-                let should_exclude = self
-                    .config
-                    .exclude_keywords
-                    .iter()
-                    .any(|key| title.to_lowercase().contains(&key.to_lowercase()));
-
-                if should_exclude {
-                    return None;
-                }
 
                 let company = self.extract_company(&body_doc).unwrap_or_default();
                 let experience_years = self.extract_experience_years(&body_doc).unwrap_or_default();
+                let experience = self.extract_experience(&body_doc);
                 let location = self.extract_location(&body_doc).unwrap_or_default();
+                let region = self.extract_region(&body_doc);
 
                 let url = body_element
                     .parent()
@@ -138,22 +685,24 @@ impl JobListInfiniteScrollCrawler for WantedClient {
                     .map(|href| format!("{}{}", self.base_url, href))
                     .unwrap_or_default();
 
-                Some(Job {
+                Job {
                     title,
                     company,
                     experience_years,
+                    experience,
                     url,
                     location,
+                    region,
                     ..Default::default()
-                })
+                }
             })
             .collect();
 
         Ok(jobs)
     }
 
-    fn go_next_page(&self, tab: &Arc<Tab>) -> Result<()> {
-        tab.evaluate("window.scrollTo(0, document.body.scrollHeight)", false)?;
+    fn go_next_page(&self, page: &dyn PageFetcher) -> Result<()> {
+        page.evaluate("window.scrollTo(0, document.body.scrollHeight)")?;
         std::thread::sleep(Duration::from_secs(2));
         Ok(())
     }
@@ -215,6 +764,43 @@ impl JobFieldExtractor for WantedClient {
 
         Some(location_exp)
     }
+
+    fn extract_salary(&self, fragment: &Html) -> Option<Salary> {
+        // 목록 카드에는 연봉 정보가 없고 상세 페이지 본문에서만 찾을 수 있음
+        fragment
+            .root_element()
+            .text()
+            .filter(|text| text.contains("연봉") || text.contains("급여"))
+            .find_map(parse_salary)
+    }
+
+    fn extract_tech_stack(&self, fragment: &Html) -> Vec<String> {
+        let Ok(heading_selector) = Selector::parse("h2, h3, h4, strong, dt") else {
+            return Vec::new();
+        };
+        let Ok(tag_selector) = Selector::parse("li, span, button") else {
+            return Vec::new();
+        };
+
+        // 기술스택 태그 영역을 먼저 보고, 본문에서 찾은 기술은 뒤에 덧붙임
+        let mut tech_stack = Vec::new();
+        for heading in fragment.select(&heading_selector) {
+            let heading_text = heading.text().collect::<String>().replace(' ', "");
+            if !heading_text.contains("기술스택") {
+                continue;
+            }
+            let Some(section) = heading.parent().and_then(ElementRef::wrap) else {
+                continue;
+            };
+            let tags = section
+                .select(&tag_selector)
+                .filter_map(|tag| normalize_tech(&tag.text().collect::<String>()))
+                .map(str::to_string);
+            merge_tech_stack(&mut tech_stack, tags);
+        }
+
+        tech_stack
+    }
 }
 
 impl Default for WantedClient {
@@ -224,15 +810,27 @@ impl Default for WantedClient {
 }
 
 impl Crawler for WantedClient {
-    fn start_crawl(&self) -> Result<Vec<Job>> {
-        let url = self.build_url();
-        let browser = self
-            .create_browser()
+    fn start_crawl(&self) -> Result<CrawlOutput> {
+        if self.config.mode == WantedCrawlMode::Api {
+            println!("원티드 채용공고 목록 API 수집 시작..",);
+            return self
+                .fetch_api_jobs()
+                .inspect(|output| {
+                    println!("\n✅ 원티드 채용공고 {}개 수집 완료", output.jobs.len())
+                })
+                .inspect_err(|e| eprintln!("❌ 원티드 채용공고 수집 실패: {}", e));
+        }
+
+        let url = self
+            .build_url()
+            .inspect_err(|e| eprintln!("❌ 원티드 채용공고 수집 실패: {}", e))?;
+        let backend = self
+            .create_backend()
             .inspect_err(|e| eprintln!("❌ 원티드 채용공고 수집 실패: {}", e))?;
 
         println!("원티드 채용공고 목록 수집 시작..",);
-        self.fetch_all_jobs(&browser, &url, self.config.total_pages)
-            .inspect(|jobs| println!("\n✅ 원티드 채용공고 {}개 수집 완료", jobs.len()))
+        self.fetch_all_jobs(backend.as_ref(), &url, self.config.total_pages)
+            .inspect(|output| println!("\n✅ 원티드 채용공고 {}개 수집 완료", output.jobs.len()))
             .inspect_err(|e| eprintln!("❌ 원티드 채용공고 수집 실패: {}", e))
     }
 }
@@ -240,31 +838,25 @@ impl Crawler for WantedClient {
 impl DetailCrawler for WantedClient {
     fn fetch_job_detail(
         &self,
-        tab: &Arc<Tab>,
+        page: &dyn PageFetcher,
         job: &Job,
         config: &DetailCrawlConfig,
     ) -> Result<Option<Job>> {
-        tab.navigate_to(&job.url)?;
-        self.wait_for_detail_page_load(tab)?;
-
-        let html = tab.get_content()?;
-        let document = Html::parse_document(&html);
+        let document = self.open_detail(page, &job.url)?;
 
         let text = document.root_element().text().collect::<String>();
-        let should_include = config.includes.is_empty()
-            || config.includes.iter().any(|keyword| text.contains(keyword));
-
-        if !should_include {
+        if !config.includes_any(&text) {
             return Ok(None);
         }
 
-        let deadline = self.extract_deadline(&document);
-
-        std::thread::sleep(Duration::from_millis(500));
-
-        let mut updated_job = job.clone();
-        updated_job.deadline = deadline.unwrap_or_default();
+        let detail = JobDetail {
+            deadline: self.extract_deadline(&document),
+            salary: self.extract_salary(&document),
+            tech_tags: self.extract_tech_stack(&document),
+            description: self.extract_description(&document),
+            ..JobDetail::default()
+        };
 
-        Ok(Some(updated_job))
+        Ok(Some(detail.apply(job)))
     }
 }
@@ -1,77 +1,153 @@
+use crate::description::extract_sections;
+use crate::fetcher::{FetcherBackend, FetcherKind, PageFetcher};
+use crate::health::PageStat;
+use crate::models::{ExperienceRequirement, JobDescription, Region, Salary};
+use crate::normalizers::{
+    RegionFilter, find_tech_stack, merge_tech_stack, normalize_tech_tags, parse_experience,
+    parse_region, parse_salary,
+};
+use crate::utils::{definition_items, definition_value};
 use crate::{Job, Result};
-use headless_chrome::{Browser, LaunchOptions, Tab};
 use rayon::ThreadPoolBuilder;
 use rayon::prelude::*;
 use scraper::Html;
 use std::collections::HashMap;
 use std::collections::HashSet;
-use std::sync::Arc;
 use std::time::Duration;
 
+/// 마지막 페이지를 찾지 못했거나 최대 페이지 수를 지정하지 않았을 때 수집하는 페이지 수
+pub const DEFAULT_MAX_PAGES: usize = 10;
+
+/// 목록 수집 결과. `pages`는 목록 페이지마다 읽은 카드 수와 오류
+#[derive(Debug, Clone, Default)]
+pub struct CrawlOutput {
+    pub jobs: Vec<Job>,
+    pub pages: Vec<PageStat>,
+}
+
+/// 목록에서 읽은 공고에 적용하는 제외 키워드와 지역 조건
+#[derive(Debug, Clone, Default)]
+pub struct ListFilter {
+    exclude_keywords: Vec<String>,
+    region_filter: RegionFilter,
+}
+
+impl ListFilter {
+    pub fn new(exclude_keywords: &[&str], locations: &[&str]) -> Self {
+        Self {
+            exclude_keywords: exclude_keywords
+                .iter()
+                .map(|keyword| keyword.to_lowercase())
+                .collect(),
+            region_filter: RegionFilter::new(locations),
+        }
+    }
+
+    pub fn matches(&self, job: &Job) -> bool {
+        let title = job.title.to_lowercase();
+        !self
+            .exclude_keywords
+            .iter()
+            .any(|keyword| title.contains(keyword))
+            && self.region_filter.matches(&job.region)
+    }
+
+    pub fn apply(&self, jobs: Vec<Job>) -> Vec<Job> {
+        jobs.into_iter().filter(|job| self.matches(job)).collect()
+    }
+}
+
 pub trait JobCrawler {
-    fn create_browser(&self) -> Result<Browser> {
-        let user_agent = "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36";
-        Browser::new(LaunchOptions {
-            headless: true,
-            args: vec![
-                &std::ffi::OsString::from(format!("--user-agent={}", user_agent)),
-                &std::ffi::OsString::from("--disable-blink-features=AutomationControlled"),
-            ],
-            ..Default::default()
-        })
-        .map_err(Into::into)
-    }
-
-    fn wait_for_list_page_load(&self, _tab: &Arc<Tab>) -> Result<()> {
+    fn site_name(&self) -> &str;
+
+    fn fetcher_kind(&self) -> FetcherKind {
+        FetcherKind::Chrome
+    }
+
+    /// 목록 카드에 적용할 조건. 페이지별 카드 수는 조건을 적용하기 전에 기록함
+    fn list_filter(&self) -> ListFilter {
+        ListFilter::default()
+    }
+
+    fn create_backend(&self) -> Result<Box<dyn FetcherBackend>> {
+        self.fetcher_kind().launch(self.site_name())
+    }
+
+    fn create_pages(
+        &self,
+        backend: &dyn FetcherBackend,
+        count: usize,
+    ) -> Result<HashMap<usize, Box<dyn PageFetcher>>> {
+        (0..count)
+            .map(|i| backend.new_page().map(|page| (i, page)))
+            .collect()
+    }
+
+    fn wait_for_list_page_load(&self, _page: &dyn PageFetcher) -> Result<()> {
         Ok(())
     }
 
-    fn wait_for_detail_page_load(&self, _tab: &Arc<Tab>) -> Result<()> {
+    fn wait_for_detail_page_load(&self, _page: &dyn PageFetcher) -> Result<()> {
         Ok(())
     }
 }
 
 pub trait JobListInfiniteScrollCrawler: JobCrawler {
-    fn go_next_page(&self, tab: &Arc<Tab>) -> Result<()>;
+    fn go_next_page(&self, page: &dyn PageFetcher) -> Result<()>;
 
+    /// 목록의 카드를 모두 읽음. 제외 키워드와 지역 조건은 `list_filter`로 따로 적용
     fn parse_html(&self, html: &str) -> Result<Vec<Job>>;
 
     fn fetch_all_jobs(
         &self,
-        browser: &headless_chrome::Browser,
+        backend: &dyn FetcherBackend,
         url: &str,
         total_pages: usize,
-    ) -> Result<Vec<Job>> {
-        let tab = browser.new_tab()?;
-        tab.navigate_to(url)?;
-        self.wait_for_list_page_load(&tab)?;
+    ) -> Result<CrawlOutput> {
+        let page = backend.new_page()?;
+        page.navigate_to(url)?;
+        self.wait_for_list_page_load(page.as_ref())?;
 
+        let filter = self.list_filter();
         let mut seen_url = HashSet::new();
-        let mut all_jobs = Vec::new();
+        let mut output = CrawlOutput::default();
         let mut no_new_count = 0;
 
         for current_page in 1..=total_pages {
-            let new_jobs: Vec<_> = tab
-                .get_content()
-                .ok()
-                .and_then(|html| self.parse_html(&html).ok())
-                .unwrap_or_else(|| {
-                    eprintln!("페이지 {}: 처리 실패", current_page);
-                    Vec::new()
-                })
-                .into_iter()
-                .filter(|job| seen_url.insert(job.url.clone()))
-                .collect();
+            // 스크롤할 때마다 이전 카드가 그대로 남아 있으므로 새로 나타난 카드만 셈
+            let parsed = page.get_content().and_then(|html| self.parse_html(&html));
+            let new_cards = parsed.as_ref().map(|cards| {
+                cards
+                    .iter()
+                    .filter(|job| seen_url.insert(job.url.clone()))
+                    .cloned()
+                    .collect::<Vec<_>>()
+            });
+            output.pages.push(PageStat::new(
+                url,
+                current_page,
+                new_cards.as_ref().map(Vec::len).map_err(|e| *e),
+            ));
+
+            let new_cards = new_cards.unwrap_or_else(|_| {
+                eprintln!("페이지 {}: 처리 실패", current_page);
+                Vec::new()
+            });
+            no_new_count = if new_cards.is_empty() {
+                no_new_count + 1
+            } else {
+                0
+            };
 
+            let new_jobs = filter.apply(new_cards);
             let new_count = new_jobs.len();
-            all_jobs.extend(new_jobs);
-            no_new_count = if new_count == 0 { no_new_count + 1 } else { 0 };
+            output.jobs.extend(new_jobs);
 
             println!(
                 "페이지 {}: 신규 {}개, 총 {}개 수집",
                 current_page,
                 new_count,
-                all_jobs.len()
+                output.jobs.len()
             );
 
             if no_new_count >= 2 {
@@ -79,66 +155,122 @@ pub trait JobListInfiniteScrollCrawler: JobCrawler {
                 break;
             }
             if current_page < total_pages
-                && let Err(e) = self.go_next_page(&tab)
+                && let Err(e) = self.go_next_page(page.as_ref())
             {
                 eprintln!("페이지 추가 로드 실패: 크롤링 종료: {}", e);
                 break;
             }
         }
 
-        Ok(all_jobs)
+        Ok(output)
     }
 }
 
 pub trait JobListPaginatedCrawler: JobCrawler + Sync {
     fn build_page_url(&self, base_url: &str, page: usize) -> String;
 
+    /// 목록의 카드를 모두 읽음. 제외 키워드와 지역 조건은 `list_filter`로 따로 적용
     fn parse_html(&self, html: &str) -> Result<Vec<Job>>;
 
-    fn fetch_jobs(&self, tab: &Arc<Tab>, url: &str) -> Result<Vec<Job>> {
-        tab.navigate_to(url)?;
-        self.wait_for_list_page_load(tab)?;
-        let html = tab.get_content()?;
+    /// 첫 페이지의 총 공고 수나 페이지 목록으로 마지막 페이지를 계산. 알 수 없으면 None
+    fn parse_last_page(&self, _html: &str) -> Option<usize> {
+        None
+    }
+
+    fn fetch_html(&self, page: &dyn PageFetcher, url: &str) -> Result<String> {
+        page.navigate_to(url)?;
+        self.wait_for_list_page_load(page)?;
+        let html = page.get_content()?;
         std::thread::sleep(Duration::from_millis(500));
+        Ok(html)
+    }
+
+    fn fetch_jobs(&self, page: &dyn PageFetcher, url: &str) -> Result<Vec<Job>> {
+        let html = self.fetch_html(page, url)?;
         self.parse_html(&html)
     }
 
+    /// 첫 페이지를 먼저 읽어 마지막 페이지를 정하고 나머지 페이지를 병렬로 수집.
+    /// `max_pages`를 지정하면 찾은 마지막 페이지와 관계없이 그 페이지까지만 수집
     fn fetch_all_jobs(
         &self,
-        browser: &headless_chrome::Browser,
+        backend: &dyn FetcherBackend,
         url: &str,
-        total_pages: usize,
+        max_pages: Option<usize>,
         num_threads: usize,
-    ) -> Result<Vec<Job>> {
+    ) -> Result<CrawlOutput> {
         let pool = ThreadPoolBuilder::new().num_threads(num_threads).build()?;
-        let tabs: HashMap<_, _> = (0..num_threads)
-            .map(|i| (i, browser.new_tab().unwrap()))
-            .collect();
+        let pages = self.create_pages(backend, num_threads)?;
+        let filter = self.list_filter();
+
+        let first_url = self.build_page_url(url, 1);
+        let first_page = self
+            .fetch_html(pages[&0].as_ref(), &first_url)
+            .and_then(|html| Ok((self.parse_html(&html)?, self.parse_last_page(&html))));
+        let first_stat = PageStat::new(
+            &first_url,
+            1,
+            first_page.as_ref().map(|(cards, _)| cards.len()),
+        );
+
+        let (first_cards, detected_page) = match first_page {
+            Ok(result) => {
+                println!("[Thread 0] 완료: 페이지 1");
+                result
+            }
+            Err(e) => {
+                eprintln!("[Thread 0] 실패: (페이지 1): {}", e);
+                (Vec::new(), None)
+            }
+        };
+
+        let last_page = match (detected_page, max_pages) {
+            (Some(detected), Some(max)) => detected.min(max),
+            (Some(detected), None) => detected,
+            (None, Some(max)) => max,
+            (None, None) => {
+                eprintln!(
+                    "마지막 페이지를 찾지 못해 {}페이지까지 수집",
+                    DEFAULT_MAX_PAGES
+                );
+                DEFAULT_MAX_PAGES
+            }
+        };
+        println!("총 {}페이지 수집", last_page);
 
-        let all_jobs: Vec<Job> = pool.install(|| {
-            (1..=total_pages)
+        let rest: Vec<(Vec<Job>, PageStat)> = pool.install(|| {
+            (2..=last_page)
                 .into_par_iter()
-                .flat_map(|page| {
+                .map(|page| {
                     let thread_index = rayon::current_thread_index().unwrap();
-                    let tab = &tabs[&thread_index];
+                    let fetcher = pages[&thread_index].as_ref();
                     let url = self.build_page_url(url, page);
-                    let result = self.fetch_jobs(tab, &url);
+                    let result = self.fetch_jobs(fetcher, &url);
+                    let stat = PageStat::new(&url, page, result.as_ref().map(Vec::len));
 
                     match result {
-                        Ok(page_jobs) => {
+                        Ok(cards) => {
                             println!("[Thread {:?}] 완료: 페이지 {}", thread_index, page);
-                            page_jobs
+                            (cards, stat)
                         }
                         Err(e) => {
                             eprintln!("[Thread {:?}] 실패: (페이지 {}): {}", thread_index, page, e);
-                            Vec::new()
+                            (Vec::new(), stat)
                         }
                     }
                 })
                 .collect()
         });
 
-        Ok(all_jobs)
+        let mut output = CrawlOutput {
+            jobs: filter.apply(first_cards),
+            pages: vec![first_stat],
+        };
+        for (cards, stat) in rest {
+            output.jobs.extend(filter.apply(cards));
+            output.pages.push(stat);
+        }
+        Ok(output)
     }
 }
 
@@ -146,38 +278,142 @@ pub trait JobFieldExtractor {
     fn extract_title(&self, fragment: &Html) -> Option<String>;
     fn extract_company(&self, fragment: &Html) -> Option<String>;
     fn extract_experience_years(&self, fragment: &Html) -> Option<String>;
+
+    fn extract_experience(&self, fragment: &Html) -> ExperienceRequirement {
+        self.extract_experience_years(fragment)
+            .map(|text| parse_experience(&text))
+            .unwrap_or_default()
+    }
+
     fn extract_deadline(&self, fragment: &Html) -> Option<String>;
     fn extract_location(&self, fragment: &Html) -> Option<String>;
+
+    fn extract_region(&self, fragment: &Html) -> Region {
+        self.extract_location(fragment)
+            .map(|text| parse_region(&text))
+            .unwrap_or_default()
+    }
+
     fn extract_url(&self, fragment: &Html) -> Option<String>;
+    fn extract_salary(&self, fragment: &Html) -> Option<Salary>;
+
+    fn extract_tech_stack(&self, _fragment: &Html) -> Vec<String> {
+        Vec::new()
+    }
+
+    fn extract_description(&self, fragment: &Html) -> JobDescription {
+        extract_sections(fragment)
+    }
 }
 
 pub struct DetailCrawlConfig {
     pub thread_count: usize,
     pub includes: Vec<&'static str>,
+    /// 스레드마다 상세 페이지 하나를 가져온 뒤 기다리는 시간. 기본값은 기다리지 않음
+    pub delay: Duration,
+}
+
+impl Default for DetailCrawlConfig {
+    fn default() -> Self {
+        Self {
+            thread_count: 1,
+            includes: Vec::new(),
+            delay: Duration::ZERO,
+        }
+    }
+}
+
+impl DetailCrawlConfig {
+    /// `includes`가 비어 있거나 키워드가 하나라도 본문에 있으면 수집 대상
+    pub fn includes_any(&self, text: &str) -> bool {
+        self.includes.is_empty() || self.includes.iter().any(|keyword| text.contains(keyword))
+    }
+}
+
+/// 상세 페이지 요약(`<dt>항목</dt><dd>값</dd>`)에서 읽을 항목 이름. 사이트에 없는 항목은 None
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DetailLabels {
+    pub deadline: Option<&'static str>,
+    pub salary: Option<&'static str>,
+    pub employment_type: Option<&'static str>,
+    pub tech_stack: Option<&'static str>,
+}
+
+/// 상세 페이지에서 읽은 값. 찾은 항목만 목록에서 읽은 값을 덮어씀
+#[derive(Debug, Clone, Default)]
+pub struct JobDetail {
+    pub deadline: Option<String>,
+    pub salary: Option<Salary>,
+    pub employment_type: Option<String>,
+    /// 상세 페이지의 기술스택 태그. 본문에서 찾은 기술은 `apply`에서 뒤에 덧붙임
+    pub tech_tags: Vec<String>,
+    pub description: JobDescription,
+}
+
+impl JobDetail {
+    /// 요약 항목에서 값을 읽음. 본문은 사이트마다 위치가 달라 호출하는 쪽에서 채움
+    pub fn from_definitions(document: &Html, labels: &DetailLabels) -> Self {
+        let value = |label: Option<&str>| label.and_then(|label| definition_value(document, label));
+        Self {
+            deadline: value(labels.deadline),
+            salary: value(labels.salary).and_then(|text| parse_salary(&text)),
+            employment_type: value(labels.employment_type),
+            tech_tags: labels
+                .tech_stack
+                .map(|label| normalize_tech_tags(definition_items(document, label)))
+                .unwrap_or_default(),
+            description: JobDescription::default(),
+        }
+    }
+
+    pub fn apply(self, job: &Job) -> Job {
+        let mut job = job.clone();
+        if let Some(deadline) = self.deadline {
+            job.deadline = deadline;
+        }
+        if let Some(salary) = self.salary {
+            job.salary = Some(salary);
+        }
+        if let Some(employment_type) = self.employment_type {
+            job.employment_type = employment_type;
+        }
+        merge_tech_stack(&mut job.tech_stack, self.tech_tags);
+        merge_tech_stack(
+            &mut job.tech_stack,
+            find_tech_stack(&self.description.skill_text()),
+        );
+        job.description = self.description;
+        job
+    }
 }
 
 pub trait DetailCrawler: Sync + JobCrawler {
     fn fetch_job_detail(
         &self,
-        _tab: &Arc<Tab>,
+        _page: &dyn PageFetcher,
         job: &Job,
         _config: &DetailCrawlConfig,
     ) -> Result<Option<Job>> {
         Ok(Some(job.clone()))
     }
 
+    /// 상세 페이지로 이동해 로드를 기다린 뒤 문서를 읽음
+    fn open_detail(&self, page: &dyn PageFetcher, url: &str) -> Result<Html> {
+        page.navigate_to(url)?;
+        self.wait_for_detail_page_load(page)?;
+        Ok(Html::parse_document(&page.get_content()?))
+    }
+
     fn crawl_job_details(&self, jobs: Vec<Job>, config: &DetailCrawlConfig) -> Result<Vec<Job>> {
-        let browser = self
-            .create_browser()
+        let backend = self
+            .create_backend()
             .inspect_err(|e| eprintln!("❌ 브라우저 생성 실패: {}", e))?;
 
         let pool = ThreadPoolBuilder::new()
             .num_threads(config.thread_count)
             .build()?;
 
-        let tabs: HashMap<_, _> = (0..config.thread_count)
-            .map(|i| (i, browser.new_tab().unwrap()))
-            .collect();
+        let pages = self.create_pages(backend.as_ref(), config.thread_count)?;
 
         let total = jobs.len();
         let counter = std::sync::atomic::AtomicUsize::new(0);
@@ -186,10 +422,15 @@ pub trait DetailCrawler: Sync + JobCrawler {
             jobs.into_par_iter()
                 .filter_map(|job| {
                     let thread_index = rayon::current_thread_index().unwrap();
-                    let tab = &tabs[&thread_index];
+                    let page = pages[&thread_index].as_ref();
                     let count = counter.fetch_add(1, std::sync::atomic::Ordering::Relaxed) + 1;
 
-                    match self.fetch_job_detail(tab, &job, config) {
+                    let result = self.fetch_job_detail(page, &job, config);
+                    if !config.delay.is_zero() {
+                        std::thread::sleep(config.delay);
+                    }
+
+                    match result {
                         Ok(Some(updated_job)) => {
                             println!(
                                 "[Thread {:?}] {}/{} 완료: {}",
@@ -0,0 +1,245 @@
+use crate::models::JobDescription;
+use scraper::node::Node;
+use scraper::{ElementRef, Html};
+
+/// 알 수 없는 제목이면 이전 섹션을 끝내는 태그
+const HEADING_TAGS: [&str; 7] = ["h1", "h2", "h3", "h4", "h5", "h6", "dt"];
+
+/// 문장 중간에 쓰이는 태그. 안의 텍스트는 둘러싼 블록 요소의 한 줄로 이어 붙임
+const INLINE_TAGS: [&str; 14] = [
+    "a", "b", "strong", "em", "i", "u", "span", "mark", "small", "code", "font", "s", "sub", "sup",
+];
+
+#[derive(Clone, Copy)]
+enum Section {
+    MainTasks,
+    Requirements,
+    Preferred,
+    Benefits,
+    HiringProcess,
+}
+
+const SECTION_HEADINGS: [(Section, &[&str]); 5] = [
+    (
+        Section::MainTasks,
+        &["주요업무", "담당업무", "업무내용", "하는일", "이런일을해요"],
+    ),
+    (
+        Section::Requirements,
+        &[
+            "자격요건",
+            "지원자격",
+            "필수요건",
+            "필수자격",
+            "이런분을찾아요",
+        ],
+    ),
+    (
+        Section::Preferred,
+        &["우대사항", "우대조건", "이런분이면더좋아요"],
+    ),
+    (
+        Section::Benefits,
+        &["혜택및복지", "복리후생", "복지및혜택", "복지", "혜택"],
+    ),
+    (
+        Section::HiringProcess,
+        &["채용절차", "채용전형", "전형절차", "채용프로세스"],
+    ),
+];
+
+/// 상세 페이지 본문을 제목(주요업무, 자격요건, 우대사항, 혜택 및 복지, 채용 절차) 기준으로 나눔
+pub fn extract_sections(document: &Html) -> JobDescription {
+    let mut description = JobDescription::default();
+    let mut current = None;
+
+    for block in text_blocks(document) {
+        for line in block
+            .text
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+        {
+            match find_section(line) {
+                Some(section) => current = Some(section),
+                // 알 수 없는 제목(마감일, 근무지역 등)이 나오면 이전 섹션을 끝냄.
+                // 본문 중간의 굵은 글씨(strong, b)는 제목으로 보지 않음
+                None if block.heading => current = None,
+                None => {
+                    if let Some(section) = current {
+                        append_line(section_text(&mut description, section), line);
+                    }
+                }
+            }
+        }
+    }
+
+    description
+}
+
+/// 같은 블록 요소 안의 텍스트 한 덩어리. `<a>`, `<strong>` 등으로 나뉜 문장도 한 줄로 이어 붙임
+struct TextBlock {
+    text: String,
+    heading: bool,
+}
+
+fn text_blocks(document: &Html) -> Vec<TextBlock> {
+    let mut blocks = Vec::new();
+    let mut current: Option<(ElementRef, TextBlock)> = None;
+
+    for node in document.root_element().descendants() {
+        match node.value() {
+            Node::Element(element) if element.name() == "br" => {
+                if let Some((_, block)) = current.as_mut() {
+                    block.text.push('\n');
+                }
+            }
+            Node::Text(text) => {
+                let mut block_element = None;
+                let mut hidden = false;
+                for element in node.ancestors().filter_map(ElementRef::wrap) {
+                    let name = element.value().name();
+                    if block_element.is_none() && !INLINE_TAGS.contains(&name) {
+                        block_element = Some(element);
+                    }
+                    hidden |= matches!(name, "script" | "style" | "head");
+                }
+                let Some(element) = block_element.filter(|_| !hidden) else {
+                    continue;
+                };
+
+                if current
+                    .as_ref()
+                    .is_none_or(|(current, _)| *current != element)
+                {
+                    blocks.extend(current.take().map(|(_, block)| block));
+                    current = Some((
+                        element,
+                        TextBlock {
+                            text: String::new(),
+                            heading: HEADING_TAGS.contains(&element.value().name()),
+                        },
+                    ));
+                }
+                if let Some((_, block)) = current.as_mut() {
+                    push_collapsed(&mut block.text, text);
+                }
+            }
+            _ => {}
+        }
+    }
+    blocks.extend(current.map(|(_, block)| block));
+
+    blocks
+}
+
+/// 줄바꿈은 그대로 두고 HTML 들여쓰기 등으로 이어진 공백은 하나로 줄임
+fn push_collapsed(target: &mut String, text: &str) {
+    for c in text.chars() {
+        if c == '\n' || !c.is_whitespace() {
+            target.push(c);
+        } else if !target.ends_with([' ', '\n']) && !target.is_empty() {
+            target.push(' ');
+        }
+    }
+}
+
+fn find_section(text: &str) -> Option<Section> {
+    let normalized: String = text.chars().filter(|c| c.is_alphanumeric()).collect();
+    if normalized.chars().count() > 12 {
+        return None;
+    }
+
+    SECTION_HEADINGS
+        .iter()
+        .find(|(_, headings)| headings.contains(&normalized.as_str()))
+        .map(|(section, _)| *section)
+}
+
+fn section_text(description: &mut JobDescription, section: Section) -> &mut String {
+    match section {
+        Section::MainTasks => &mut description.main_tasks,
+        Section::Requirements => &mut description.requirements,
+        Section::Preferred => &mut description.preferred,
+        Section::Benefits => &mut description.benefits,
+        Section::HiringProcess => &mut description.hiring_process,
+    }
+}
+
+fn append_line(target: &mut String, line: &str) {
+    if !target.is_empty() {
+        target.push('\n');
+    }
+    target.push_str(line);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sections(body: &str) -> JobDescription {
+        extract_sections(&Html::parse_document(&format!("<body>{}</body>", body)))
+    }
+
+    #[test]
+    fn keeps_section_open_across_inline_bold() {
+        let description = sections(
+            "<h3>주요업무</h3>\
+             <p><strong>프론트엔드</strong> 웹 서비스 개발</p>\
+             <p><b>중요</b></p>\
+             <p>디자인 시스템 관리</p>",
+        );
+
+        assert_eq!(
+            description.main_tasks,
+            "프론트엔드 웹 서비스 개발\n중요\n디자인 시스템 관리"
+        );
+    }
+
+    #[test]
+    fn joins_sentence_split_by_inline_tags() {
+        let description = sections(
+            "<h3>자격요건</h3>\
+             <ul>\
+               <li><a href=\"https://react.dev\">React</a>와 <strong>TypeScript</strong> 경험</li>\
+               <li>협업 도구 사용 경험<br>(Jira, Slack)</li>\
+             </ul>",
+        );
+
+        assert_eq!(
+            description.requirements,
+            "React와 TypeScript 경험\n협업 도구 사용 경험\n(Jira, Slack)"
+        );
+    }
+
+    #[test]
+    fn bold_heading_opens_section_only_when_known() {
+        let description = sections(
+            "<p><strong>우대사항</strong></p>\
+             <p>Next.js 경험</p>\
+             <p><strong>근무 조건</strong></p>\
+             <p>주 5일 근무</p>\
+             <h3>근무지역</h3>\
+             <p>서울 강남구</p>",
+        );
+
+        assert_eq!(
+            description.preferred,
+            "Next.js 경험\n근무 조건\n주 5일 근무"
+        );
+    }
+
+    #[test]
+    fn unknown_heading_ends_section() {
+        let description = sections(
+            "<h3>혜택 및 복지</h3>\
+             <p>자기계발비 지원</p>\
+             <dl><dt>마감일</dt><dd>2025-12-31</dd></dl>\
+             <h3>채용 절차</h3>\
+             <p>서류 전형 &gt; 면접</p>",
+        );
+
+        assert_eq!(description.benefits, "자기계발비 지원");
+        assert_eq!(description.hiring_process, "서류 전형 > 면접");
+    }
+}
@@ -1,80 +1,180 @@
+use crate::cache::{CachedEnrichment, EnrichmentCache};
+use crate::crawler::JobCrawler;
+use crate::fetcher::{FetcherBackend, FetcherKind, PageFetcher};
+use crate::models::CompanyAttribute;
 use crate::utils::random_delay;
 use crate::{Job, Result};
-use headless_chrome::Tab;
+use chrono::Utc;
 use rayon::ThreadPoolBuilder;
 use rayon::prelude::*;
 use regex::Regex;
-use scraper::Html;
-use std::collections::HashMap;
-use std::sync::Arc;
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// 회사명 앞뒤에 붙는 법인 표기. 괄호로 감싼 표기는 괄호째 지움
+const CORPORATE_MARKERS: [&str; 5] = ["주식회사", "㈜", "유한회사", "㈔", "사단법인"];
 
 pub struct EnricherConfig {
     pub thread_count: usize,
+    /// 회사별 조회 결과를 저장할 JSON 파일. None이면 한 번 실행하는 동안만 회사별로 한 번 조회
+    pub cache_path: Option<PathBuf>,
+    /// 캐시된 결과를 다시 조회하지 않고 쓰는 기간
+    pub cache_ttl: Duration,
+    pub fetcher: FetcherKind,
 }
 
-pub trait JobEnricher: Sync {
+impl Default for EnricherConfig {
+    fn default() -> Self {
+        Self {
+            thread_count: 1,
+            cache_path: None,
+            cache_ttl: Duration::from_secs(7 * 24 * 60 * 60),
+            fetcher: FetcherKind::Chrome,
+        }
+    }
+}
+
+/// 보강 사이트가 회사 하나에 대해 돌려주는 값. 키는 csv 컬럼 이름으로 사용하고,
+/// 찾지 못한 항목은 넣지 않음
+pub type CompanyFields = Vec<(&'static str, String)>;
+
+pub trait JobEnricher: JobCrawler + Sync {
     fn start_enrich(&self, jobs: &[Job]) -> Result<Vec<Job>>;
 
-    fn enrich_all_jobs(
-        &self,
-        browser: &headless_chrome::Browser,
-        jobs: &[Job],
-        thread_count: usize,
-    ) -> Result<Vec<Job>> {
-        let pool = ThreadPoolBuilder::new().num_threads(thread_count).build()?;
-        let tabs: HashMap<_, _> = (0..thread_count)
-            .map(|i| (i, browser.new_tab().unwrap()))
+    fn config(&self) -> &EnricherConfig;
+
+    /// 정규화된 회사명마다 한 번만 조회하고, 캐시에 `cache_ttl` 안의 결과가 있으면 조회하지 않음.
+    /// 조회에 실패했거나 아무 항목도 찾지 못한 회사는 기간이 지난 캐시 결과라도 있으면 그것을 사용
+    fn enrich_all_jobs(&self, backend: &dyn FetcherBackend, jobs: &[Job]) -> Result<Vec<Job>> {
+        let config = self.config();
+        let site = self.site_name();
+        let now = Utc::now();
+        let mut cache = config
+            .cache_path
+            .as_ref()
+            .map(EnrichmentCache::load)
+            .unwrap_or_default();
+
+        let companies: BTreeSet<String> = jobs
+            .iter()
+            .map(|job| self.normalize_company_name(&job.company))
+            .filter(|company| !company.is_empty())
             .collect();
+        let (cached, targets): (Vec<String>, Vec<String>) =
+            companies.into_iter().partition(|company| {
+                cache
+                    .get_fresh(site, company, config.cache_ttl, now)
+                    .is_some()
+            });
 
-        let enriched_jobs = pool.install(|| {
-            jobs.par_iter()
-                .map(|job| {
-                    let mut job = job.clone();
-                    let thread_idx = rayon::current_thread_index().unwrap();
-                    let tab = &tabs[&thread_idx];
-
-                    let normalized_company = self.normalize_company_name(&job.company);
-                    let url = self.build_url(&normalized_company);
-
-                    match self.fetch_rate_and_reviews(tab, &url) {
-                        Ok((rating, review_count)) => {
-                            println!("[Thread {:?}] 완료: {}", thread_idx, normalized_company);
-                            job.rating = rating;
-                            job.review_count = review_count;
-                        }
-                        Err(e) => {
-                            eprintln!(
-                                "[Thread {:?}] 실패 ({}): {}",
-                                thread_idx, normalized_company, e
-                            );
-                        }
-                    }
+        if !targets.is_empty() {
+            let pool = ThreadPoolBuilder::new()
+                .num_threads(config.thread_count)
+                .build()?;
+            let pages = self.create_pages(backend, config.thread_count)?;
 
-                    random_delay();
-                    job
-                })
-                .collect()
-        });
+            let fetched: Vec<(String, CachedEnrichment)> = pool.install(|| {
+                targets
+                    .par_iter()
+                    .filter_map(|company| {
+                        let thread_idx = rayon::current_thread_index().unwrap();
+                        let page = pages[&thread_idx].as_ref();
+                        let url = self.build_url(company);
+
+                        let result = match self.fetch_company_fields(page, &url) {
+                            // 회사 페이지가 없거나 구조가 바뀐 경우라 캐시하지 않고 다음 실행에서 다시 조회
+                            Ok(fields) if fields.is_empty() => {
+                                eprintln!("[Thread {:?}] 정보 없음: {}", thread_idx, company);
+                                None
+                            }
+                            Ok(fields) => {
+                                println!("[Thread {:?}] 완료: {}", thread_idx, company);
+                                let fetched_at = Utc::now();
+                                let attributes = fields
+                                    .into_iter()
+                                    .map(|(key, value)| CompanyAttribute {
+                                        key: key.to_string(),
+                                        value,
+                                        source: site.to_string(),
+                                        fetched_at,
+                                    })
+                                    .collect();
+                                Some((
+                                    company.clone(),
+                                    CachedEnrichment {
+                                        attributes,
+                                        fetched_at,
+                                    },
+                                ))
+                            }
+                            Err(e) => {
+                                eprintln!("[Thread {:?}] 실패 ({}): {}", thread_idx, company, e);
+                                None
+                            }
+                        };
+
+                        random_delay();
+                        result
+                    })
+                    .collect()
+            });
+
+            for (company, entry) in fetched {
+                cache.insert(site, &company, entry);
+            }
+        }
+
+        println!(
+            "{} 회사 {}곳 조회, {}곳 캐시 사용",
+            site,
+            targets.len(),
+            cached.len()
+        );
+
+        if let Some(path) = &config.cache_path
+            && let Err(e) = cache.save(path)
+        {
+            eprintln!("❌ 캐시 저장 실패 ({}): {}", path.display(), e);
+        }
+
+        let enriched_jobs = jobs
+            .iter()
+            .map(|job| {
+                let mut job = job.clone();
+                let normalized_company = self.normalize_company_name(&job.company);
+                if let Some(entry) = cache.get(site, &normalized_company) {
+                    for attribute in &entry.attributes {
+                        job.set_attribute(attribute.clone());
+                    }
+                }
+                job
+            })
+            .collect();
 
         Ok(enriched_jobs)
     }
 
-    fn fetch_rate_and_reviews(
-        &self,
-        tab: &Arc<Tab>,
-        url: &str,
-    ) -> Result<(Option<String>, Option<u32>)>;
+    /// 회사 페이지 하나를 열어 회사 정보를 수집
+    fn fetch_company_fields(&self, page: &dyn PageFetcher, url: &str) -> Result<CompanyFields>;
 
     fn build_url(&self, company: &str) -> String;
 
+    /// 괄호 안 표기("(주)", "(비바리퍼블리카)")와 "㈜", "주식회사" 같은 법인 표기를 떼어 냄
     fn normalize_company_name(&self, company: &str) -> String {
         let re = Regex::new(r"\s*[(\(（][^)\)）]*[)\)）]\s*").unwrap();
-        re.replace_all(company, "").trim().to_string()
+        let company = re.replace_all(company, "");
+        company
+            .split_whitespace()
+            .map(|word| {
+                CORPORATE_MARKERS.iter().fold(word, |word, marker| {
+                    word.trim_start_matches(marker).trim_end_matches(marker)
+                })
+            })
+            .filter(|word| !word.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ")
     }
 
-    fn parse_html(&self, html: &str) -> Result<(Option<String>, Option<u32>)>;
-
-    fn extract_rating(&self, document: &Html) -> Option<String>;
-
-    fn extract_review_count(&self, document: &Html) -> Option<u32>;
+    fn parse_html(&self, html: &str) -> Result<CompanyFields>;
 }
@@ -1,13 +1,27 @@
-use headless_chrome::Tab;
-use scraper::{Html, Selector};
-use std::sync::Arc;
+use scraper::{ElementRef, Html, Selector};
 use std::time::Duration;
 
 use crate::JobCrawler;
-use crate::enricher::{EnricherConfig, JobEnricher};
+use crate::enricher::{CompanyFields, EnricherConfig, JobEnricher};
+use crate::fetcher::{FetcherKind, PageFetcher};
 use crate::{Job, Result};
 use regex::Regex;
 
+/// 기업 리뷰 페이지의 항목별 평점 (페이지 표기, csv 컬럼)
+const SUB_SCORES: [(&str, &str); 5] = [
+    ("커리어 향상", "career_rating"),
+    ("워라밸", "work_life_balance_rating"),
+    ("급여 및 복지", "compensation_rating"),
+    ("사내문화", "culture_rating"),
+    ("경영진", "management_rating"),
+];
+
+/// 백분율로 표시되는 항목 (페이지 표기, csv 컬럼)
+const RATES: [(&str, &str); 2] = [("CEO 지지율", "ceo_approval"), ("추천율", "recommend_rate")];
+
+/// 장점/단점을 가져올 상위 리뷰 개수
+const TOP_REVIEW_COUNT: usize = 3;
+
 pub struct BlindEnricher {
     base_url: String,
     config: EnricherConfig,
@@ -20,43 +34,6 @@ impl BlindEnricher {
             config,
         }
     }
-}
-
-impl JobEnricher for BlindEnricher {
-    fn start_enrich(&self, jobs: &[Job]) -> Result<Vec<Job>> {
-        println!("\n블라인드 평점/리뷰 개수 수집 시작..");
-
-        let browser = self
-            .create_browser()
-            .inspect_err(|e| eprintln!("❌ 블라인드 평점/리뷰 개수 수집 실패: {}", e))?;
-
-        self.enrich_all_jobs(&browser, jobs, self.config.thread_count)
-            .inspect(|_| println!("✅ 블라인드 평점/리뷰 개수 수집 완료"))
-            .inspect_err(|e| eprintln!("❌ 블라인드 평점/리뷰 개수 수집 실패: {}", e))
-    }
-
-    fn build_url(&self, company: &str) -> String {
-        format!("{}/{}/reviews", self.base_url, company)
-    }
-
-    fn fetch_rate_and_reviews(
-        &self,
-        tab: &Arc<Tab>,
-        url: &str,
-    ) -> Result<(Option<String>, Option<u32>)> {
-        tab.navigate_to(url)?;
-
-        let html = tab.get_content()?;
-        std::thread::sleep(Duration::from_millis(500));
-        self.parse_html(&html)
-    }
-
-    fn parse_html(&self, html: &str) -> Result<(Option<String>, Option<u32>)> {
-        let document = Html::parse_document(html);
-        let rating = self.extract_rating(&document);
-        let review_count = self.extract_review_count(&document);
-        Ok((rating, review_count))
-    }
 
     fn extract_rating(&self, document: &Html) -> Option<String> {
         let selector = Selector::parse("script[type='application/ld+json']").ok()?;
@@ -86,6 +63,128 @@ impl JobEnricher for BlindEnricher {
         }
         None
     }
+
+    /// 평점 요약의 `<dt>워라밸</dt><dd>3.2</dd>` 항목. 리뷰 본문의 "워라밸 5점" 같은 문장은 보지 않음
+    fn extract_sub_scores(&self, document: &Html) -> CompanyFields {
+        let Ok(selector) = Selector::parse("section.rating_wrp dl.rating_detail dt") else {
+            return Vec::new();
+        };
+        let score_re = Regex::new(r"^\d\.\d$").unwrap();
+
+        document
+            .select(&selector)
+            .filter_map(|dt| {
+                let label = dt.text().collect::<String>();
+                let (_, key) = SUB_SCORES.iter().find(|(name, _)| *name == label.trim())?;
+                let dd = dt
+                    .next_siblings()
+                    .filter_map(ElementRef::wrap)
+                    .find(|element| element.value().name() == "dd")?;
+                let score = dd.text().collect::<String>().trim().to_string();
+                score_re.is_match(&score).then_some((*key, score))
+            })
+            .collect()
+    }
+
+    /// 평점 요약의 "CEO 지지율 81%"처럼 항목 이름 뒤에 오는 백분율 (숫자만)
+    fn extract_rates(&self, document: &Html) -> CompanyFields {
+        let Ok(selector) = Selector::parse("section.rating_wrp ul.rate_summary") else {
+            return Vec::new();
+        };
+        let text = document
+            .select(&selector)
+            .map(|summary| summary.text().collect::<Vec<_>>().join(" "))
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        RATES
+            .iter()
+            .filter_map(|(label, key)| {
+                let re = Regex::new(&format!(r"{}\s*(\d{{1,3}})\s*%", label)).ok()?;
+                let rate = re.captures(&text)?.get(1)?.as_str().to_string();
+                Some((*key, rate))
+            })
+            .collect()
+    }
+
+    /// 상위 리뷰의 장점 또는 단점 문단을 줄바꿈으로 연결
+    fn extract_review_snippets(&self, document: &Html, label: &str) -> Option<String> {
+        let selector = Selector::parse("div.review_item div.parag p").ok()?;
+
+        let snippets: Vec<String> = document
+            .select(&selector)
+            .filter_map(|paragraph| {
+                let text = paragraph.text().collect::<Vec<_>>().join(" ");
+                let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+                text.strip_prefix(label).map(|rest| rest.trim().to_string())
+            })
+            .filter(|snippet| !snippet.is_empty())
+            .take(TOP_REVIEW_COUNT)
+            .collect();
+
+        (!snippets.is_empty()).then(|| snippets.join("\n"))
+    }
+}
+
+impl JobEnricher for BlindEnricher {
+    fn start_enrich(&self, jobs: &[Job]) -> Result<Vec<Job>> {
+        println!("\n블라인드 기업 리뷰 수집 시작..");
+
+        let backend = self
+            .create_backend()
+            .inspect_err(|e| eprintln!("❌ 블라인드 기업 리뷰 수집 실패: {}", e))?;
+
+        self.enrich_all_jobs(backend.as_ref(), jobs)
+            .inspect(|_| println!("✅ 블라인드 기업 리뷰 수집 완료"))
+            .inspect_err(|e| eprintln!("❌ 블라인드 기업 리뷰 수집 실패: {}", e))
+    }
+
+    fn config(&self) -> &EnricherConfig {
+        &self.config
+    }
+
+    fn build_url(&self, company: &str) -> String {
+        format!("{}/{}/reviews", self.base_url, company)
+    }
+
+    fn fetch_company_fields(&self, page: &dyn PageFetcher, url: &str) -> Result<CompanyFields> {
+        page.navigate_to(url)?;
+
+        let html = page.get_content()?;
+        std::thread::sleep(Duration::from_millis(500));
+        self.parse_html(&html)
+    }
+
+    fn parse_html(&self, html: &str) -> Result<CompanyFields> {
+        let document = Html::parse_document(html);
+        let mut fields = Vec::new();
+
+        if let Some(rating) = self.extract_rating(&document) {
+            fields.push(("rating", rating));
+        }
+        if let Some(review_count) = self.extract_review_count(&document) {
+            fields.push(("review_count", review_count.to_string()));
+        }
+
+        fields.extend(self.extract_sub_scores(&document));
+        fields.extend(self.extract_rates(&document));
+
+        if let Some(pros) = self.extract_review_snippets(&document, "장점") {
+            fields.push(("pros", pros));
+        }
+        if let Some(cons) = self.extract_review_snippets(&document, "단점") {
+            fields.push(("cons", cons));
+        }
+        Ok(fields)
+    }
 }
 
-impl JobCrawler for BlindEnricher {}
+impl JobCrawler for BlindEnricher {
+    fn site_name(&self) -> &str {
+        "블라인드"
+    }
+
+    fn fetcher_kind(&self) -> FetcherKind {
+        self.config.fetcher.clone()
+    }
+}
@@ -0,0 +1,127 @@
+use std::fmt::Display;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("[{site}] 브라우저 실행 실패: {message}")]
+    Browser { site: String, message: String },
+
+    #[error("[{site}] 페이지 이동 실패 ({url}): {message}")]
+    Navigation {
+        site: String,
+        url: String,
+        message: String,
+    },
+
+    #[error("[{site}] 파싱 실패 ({url}): {message}")]
+    Parse {
+        site: String,
+        url: String,
+        message: String,
+    },
+
+    #[error("[{site}] 요청 차단 ({url}): {message}")]
+    Blocked {
+        site: String,
+        url: String,
+        message: String,
+    },
+
+    #[error("[{site}] 페이지 없음 ({url})")]
+    NotFound { site: String, url: String },
+
+    #[error("파일 저장 실패 ({path}): {source}")]
+    Output {
+        path: String,
+        #[source]
+        source: csv::Error,
+    },
+
+    #[error("사이트 정의 읽기 실패 ({name}): {message}")]
+    Definition { name: String, message: String },
+
+    #[error("[{site}] 검색 조건 오류: {message}")]
+    Config { site: String, message: String },
+
+    #[error("스레드 풀 생성 실패: {0}")]
+    ThreadPool(#[from] rayon::ThreadPoolBuildError),
+}
+
+impl Error {
+    pub fn browser(site: &str, message: impl Display) -> Self {
+        Self::Browser {
+            site: site.to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    pub fn navigation(site: &str, url: &str, message: impl Display) -> Self {
+        Self::Navigation {
+            site: site.to_string(),
+            url: url.to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    pub fn parse(site: &str, url: &str, message: impl Display) -> Self {
+        Self::Parse {
+            site: site.to_string(),
+            url: url.to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    pub fn blocked(site: &str, url: &str, message: impl Display) -> Self {
+        Self::Blocked {
+            site: site.to_string(),
+            url: url.to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    pub fn not_found(site: &str, url: &str) -> Self {
+        Self::NotFound {
+            site: site.to_string(),
+            url: url.to_string(),
+        }
+    }
+
+    pub fn definition(name: &str, message: impl Display) -> Self {
+        Self::Definition {
+            name: name.to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    pub fn config(site: &str, message: impl Display) -> Self {
+        Self::Config {
+            site: site.to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    pub fn site(&self) -> Option<&str> {
+        match self {
+            Self::Browser { site, .. }
+            | Self::Navigation { site, .. }
+            | Self::Parse { site, .. }
+            | Self::Blocked { site, .. }
+            | Self::NotFound { site, .. }
+            | Self::Config { site, .. } => Some(site),
+            Self::Output { .. } | Self::Definition { .. } | Self::ThreadPool(_) => None,
+        }
+    }
+
+    pub fn url(&self) -> Option<&str> {
+        match self {
+            Self::Navigation { url, .. }
+            | Self::Parse { url, .. }
+            | Self::Blocked { url, .. }
+            | Self::NotFound { url, .. } => Some(url),
+            Self::Browser { .. }
+            | Self::Config { .. }
+            | Self::Output { .. }
+            | Self::Definition { .. }
+            | Self::ThreadPool(_) => None,
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use crate::fetchers::{ChromeBackend, FixtureBackend, HttpBackend};
+use crate::{Error, Result};
+use scraper::{Html, Selector};
+use std::path::PathBuf;
+
+pub const USER_AGENT: &str = "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36";
+
+const BLOCK_MARKERS: [&str; 4] = [
+    "g-recaptcha",
+    "cf-challenge",
+    "비정상적인 접근",
+    "<title>Access Denied",
+];
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum FetcherKind {
+    #[default]
+    Chrome,
+    Http,
+    /// 디렉터리에 저장된 HTML을 URL 기준으로 재생 (네트워크 없이 테스트용)
+    Fixture(PathBuf),
+}
+
+impl FetcherKind {
+    pub fn launch(&self, site: &str) -> Result<Box<dyn FetcherBackend>> {
+        match self {
+            Self::Chrome => Ok(Box::new(ChromeBackend::launch(site)?)),
+            Self::Http => Ok(Box::new(HttpBackend::new(site))),
+            Self::Fixture(dir) => Ok(Box::new(FixtureBackend::new(site, dir))),
+        }
+    }
+}
+
+/// 페이지 하나(브라우저 탭 또는 HTTP 세션)를 다루는 추상화
+pub trait PageFetcher: Send + Sync {
+    fn navigate_to(&self, url: &str) -> Result<()>;
+
+    fn get_content(&self) -> Result<String>;
+
+    fn wait_for_element(&self, selector: &str) -> Result<()>;
+
+    fn evaluate(&self, script: &str) -> Result<()>;
+}
+
+/// 스레드마다 사용할 `PageFetcher`를 만들어 주는 백엔드
+pub trait FetcherBackend: Sync {
+    fn new_page(&self) -> Result<Box<dyn PageFetcher>>;
+}
+
+pub(crate) fn find_element(site: &str, url: &str, html: &str, selector: &str) -> Result<()> {
+    let parsed = Selector::parse(selector).map_err(|e| Error::parse(site, url, e))?;
+    let document = Html::parse_document(html);
+    match document.select(&parsed).next() {
+        Some(_) => Ok(()),
+        None => Err(Error::parse(
+            site,
+            url,
+            format!("요소를 찾을 수 없음: {}", selector),
+        )),
+    }
+}
+
+pub(crate) fn check_blocked(site: &str, url: &str, html: &str) -> Result<()> {
+    match BLOCK_MARKERS.iter().find(|marker| html.contains(*marker)) {
+        Some(marker) => Err(Error::blocked(
+            site,
+            url,
+            format!("차단 페이지 감지: {}", marker),
+        )),
+        None => Ok(()),
+    }
+}
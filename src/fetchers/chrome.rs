@@ -0,0 +1,86 @@
+use crate::fetcher::{FetcherBackend, PageFetcher, USER_AGENT, check_blocked};
+use crate::{Error, Result};
+use headless_chrome::{Browser, LaunchOptions, Tab};
+use std::ffi::OsString;
+use std::sync::Arc;
+
+pub struct ChromeBackend {
+    site: String,
+    browser: Browser,
+}
+
+impl ChromeBackend {
+    pub fn launch(site: &str) -> Result<Self> {
+        let browser = Browser::new(LaunchOptions {
+            headless: true,
+            args: vec![
+                &OsString::from(format!("--user-agent={}", USER_AGENT)),
+                &OsString::from("--disable-blink-features=AutomationControlled"),
+            ],
+            ..Default::default()
+        })
+        .map_err(|e| Error::browser(site, e))?;
+
+        Ok(Self {
+            site: site.to_string(),
+            browser,
+        })
+    }
+}
+
+impl FetcherBackend for ChromeBackend {
+    fn new_page(&self) -> Result<Box<dyn PageFetcher>> {
+        let tab = self
+            .browser
+            .new_tab()
+            .map_err(|e| Error::browser(&self.site, e))?;
+
+        Ok(Box::new(ChromeFetcher {
+            site: self.site.clone(),
+            tab,
+        }))
+    }
+}
+
+pub struct ChromeFetcher {
+    site: String,
+    tab: Arc<Tab>,
+}
+
+impl PageFetcher for ChromeFetcher {
+    fn navigate_to(&self, url: &str) -> Result<()> {
+        self.tab
+            .navigate_to(url)
+            .map_err(|e| Error::navigation(&self.site, url, e))?;
+        Ok(())
+    }
+
+    fn get_content(&self) -> Result<String> {
+        let url = self.tab.get_url();
+        let html = self
+            .tab
+            .get_content()
+            .map_err(|e| Error::navigation(&self.site, &url, e))?;
+
+        check_blocked(&self.site, &url, &html)?;
+        Ok(html)
+    }
+
+    fn wait_for_element(&self, selector: &str) -> Result<()> {
+        self.tab.wait_for_element(selector).map_err(|e| {
+            Error::parse(
+                &self.site,
+                &self.tab.get_url(),
+                format!("요소를 찾을 수 없음 ({}): {}", selector, e),
+            )
+        })?;
+        Ok(())
+    }
+
+    fn evaluate(&self, script: &str) -> Result<()> {
+        self.tab
+            .evaluate(script, false)
+            .map_err(|e| Error::browser(&self.site, e))?;
+        Ok(())
+    }
+}
@@ -0,0 +1,88 @@
+use crate::fetcher::{FetcherBackend, PageFetcher, find_element};
+use crate::{Error, Result};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+pub struct FixtureBackend {
+    site: String,
+    dir: PathBuf,
+}
+
+impl FixtureBackend {
+    pub fn new(site: &str, dir: impl Into<PathBuf>) -> Self {
+        Self {
+            site: site.to_string(),
+            dir: dir.into(),
+        }
+    }
+}
+
+impl FetcherBackend for FixtureBackend {
+    fn new_page(&self) -> Result<Box<dyn PageFetcher>> {
+        Ok(Box::new(FixtureFetcher {
+            site: self.site.clone(),
+            dir: self.dir.clone(),
+            url: Mutex::new(String::new()),
+            content: Mutex::new(String::new()),
+        }))
+    }
+}
+
+/// `fixture_file_name(url)` 이름으로 저장된 HTML 파일을 응답으로 돌려주는 fetcher
+pub struct FixtureFetcher {
+    site: String,
+    dir: PathBuf,
+    url: Mutex<String>,
+    content: Mutex<String>,
+}
+
+impl FixtureFetcher {
+    pub fn fixture_path(dir: &Path, url: &str) -> PathBuf {
+        dir.join(fixture_file_name(url))
+    }
+}
+
+impl PageFetcher for FixtureFetcher {
+    fn navigate_to(&self, url: &str) -> Result<()> {
+        let path = Self::fixture_path(&self.dir, url);
+        let html = std::fs::read_to_string(&path).map_err(|_| Error::not_found(&self.site, url))?;
+        *self.url.lock().unwrap() = url.to_string();
+        *self.content.lock().unwrap() = html;
+        Ok(())
+    }
+
+    fn get_content(&self) -> Result<String> {
+        Ok(self.content.lock().unwrap().clone())
+    }
+
+    fn wait_for_element(&self, selector: &str) -> Result<()> {
+        find_element(
+            &self.site,
+            &self.url.lock().unwrap(),
+            &self.content.lock().unwrap(),
+            selector,
+        )
+    }
+
+    fn evaluate(&self, _script: &str) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// URL에서 scheme을 떼고 파일명에 쓸 수 없는 문자를 `_`로 바꾼 fixture 파일명.
+/// 퍼센트 인코딩은 풀어서 "프론트엔드"처럼 읽을 수 있는 이름을 사용
+pub fn fixture_file_name(url: &str) -> String {
+    let url = percent_encoding::percent_decode_str(url).decode_utf8_lossy();
+    let without_scheme = url.split_once("://").map_or(&*url, |(_, rest)| rest);
+    let name: String = without_scheme
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '-' || c == '.' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    format!("{}.html", name)
+}
@@ -0,0 +1,99 @@
+use crate::fetcher::{FetcherBackend, PageFetcher, USER_AGENT, check_blocked, find_element};
+use crate::{Error, Result};
+use std::sync::Mutex;
+use std::time::Duration;
+use ureq::Agent;
+
+pub struct HttpBackend {
+    site: String,
+    agent: Agent,
+}
+
+impl HttpBackend {
+    pub fn new(site: &str) -> Self {
+        let agent = Agent::config_builder()
+            .user_agent(USER_AGENT)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build()
+            .into();
+
+        Self {
+            site: site.to_string(),
+            agent,
+        }
+    }
+}
+
+impl FetcherBackend for HttpBackend {
+    fn new_page(&self) -> Result<Box<dyn PageFetcher>> {
+        Ok(Box::new(HttpFetcher {
+            site: self.site.clone(),
+            agent: self.agent.clone(),
+            url: Mutex::new(String::new()),
+            content: Mutex::new(String::new()),
+        }))
+    }
+}
+
+/// 브라우저 없이 서버 렌더링된 HTML을 그대로 가져오는 fetcher
+pub struct HttpFetcher {
+    site: String,
+    agent: Agent,
+    url: Mutex<String>,
+    content: Mutex<String>,
+}
+
+impl PageFetcher for HttpFetcher {
+    fn navigate_to(&self, url: &str) -> Result<()> {
+        let html = self
+            .agent
+            .get(encode_url(url))
+            .call()
+            .and_then(|mut response| response.body_mut().read_to_string())
+            .map_err(|e| match e {
+                ureq::Error::StatusCode(403 | 429) => Error::blocked(&self.site, url, e),
+                ureq::Error::StatusCode(404 | 410) => Error::not_found(&self.site, url),
+                _ => Error::navigation(&self.site, url, e),
+            })?;
+
+        check_blocked(&self.site, url, &html)?;
+        *self.url.lock().unwrap() = url.to_string();
+        *self.content.lock().unwrap() = html;
+        Ok(())
+    }
+
+    fn get_content(&self) -> Result<String> {
+        Ok(self.content.lock().unwrap().clone())
+    }
+
+    fn wait_for_element(&self, selector: &str) -> Result<()> {
+        find_element(
+            &self.site,
+            &self.url.lock().unwrap(),
+            &self.content.lock().unwrap(),
+            selector,
+        )
+    }
+
+    fn evaluate(&self, _script: &str) -> Result<()> {
+        Err(Error::browser(
+            &self.site,
+            "HTTP fetcher는 스크립트 실행을 지원하지 않음",
+        ))
+    }
+}
+
+fn encode_url(url: &str) -> String {
+    let mut encoded = String::with_capacity(url.len());
+    for c in url.chars() {
+        if c.is_ascii() && c != ' ' {
+            encoded.push(c);
+        } else {
+            let mut buf = [0; 4];
+            for byte in c.encode_utf8(&mut buf).bytes() {
+                encoded.push_str(&format!("%{:02X}", byte));
+            }
+        }
+    }
+    encoded
+}
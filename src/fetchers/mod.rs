@@ -0,0 +1,7 @@
+pub mod chrome;
+pub mod fixture;
+pub mod http;
+
+pub use chrome::{ChromeBackend, ChromeFetcher};
+pub use fixture::{FixtureBackend, FixtureFetcher, fixture_file_name};
+pub use http::{HttpBackend, HttpFetcher};
@@ -0,0 +1,236 @@
+use crate::{Error, Job};
+use chrono::NaiveDate;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+/// 목록 페이지 하나의 수집 결과
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct PageStat {
+    pub url: String,
+    /// 페이지 번호. 무한 스크롤은 스크롤 횟수
+    pub page: usize,
+    /// 제외 키워드·지역 조건을 적용하기 전의 카드 수. 무한 스크롤은 그 스크롤에서 새로 나타난 카드 수
+    pub jobs: usize,
+    pub error: Option<String>,
+}
+
+impl PageStat {
+    /// `result`는 페이지에서 읽은 공고 수 또는 수집 오류
+    pub fn new(url: &str, page: usize, result: std::result::Result<usize, &Error>) -> Self {
+        Self {
+            url: url.to_string(),
+            page,
+            jobs: result.unwrap_or_default(),
+            error: result.err().map(|e| e.to_string()),
+        }
+    }
+}
+
+/// 점검한 리포트 중 이상이 하나라도 있으면 실패 종료 코드
+pub fn exit_code<'a>(reports: impl IntoIterator<Item = &'a HealthReport>) -> ExitCode {
+    if reports.into_iter().all(|report| report.healthy) {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+/// 추출률을 확인할 공고 필드
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JobField {
+    Title,
+    Company,
+    ExperienceYears,
+    Deadline,
+    Location,
+    Salary,
+    EmploymentType,
+    TechStack,
+    Url,
+}
+
+impl JobField {
+    pub const ALL: [JobField; 9] = [
+        Self::Title,
+        Self::Company,
+        Self::ExperienceYears,
+        Self::Deadline,
+        Self::Location,
+        Self::Salary,
+        Self::EmploymentType,
+        Self::TechStack,
+        Self::Url,
+    ];
+
+    /// CSV 컬럼과 같은 이름
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Title => "title",
+            Self::Company => "company",
+            Self::ExperienceYears => "experience_years",
+            Self::Deadline => "deadline",
+            Self::Location => "location",
+            Self::Salary => "salary",
+            Self::EmploymentType => "employment_type",
+            Self::TechStack => "tech_stack",
+            Self::Url => "url",
+        }
+    }
+
+    pub fn is_present(&self, job: &Job) -> bool {
+        match self {
+            Self::Title => !job.title.trim().is_empty(),
+            Self::Company => !job.company.trim().is_empty(),
+            Self::ExperienceYears => !job.experience_years.trim().is_empty(),
+            Self::Deadline => !job.deadline.trim().is_empty(),
+            Self::Location => !job.location.trim().is_empty(),
+            Self::Salary => job.salary.is_some(),
+            Self::EmploymentType => !job.employment_type.trim().is_empty(),
+            Self::TechStack => !job.tech_stack.is_empty(),
+            Self::Url => !job.url.trim().is_empty(),
+        }
+    }
+}
+
+pub struct HealthConfig {
+    /// 필드별 최소 추출률 (0.0 ~ 1.0). 목록에 없는 필드는 추출률만 기록하고 판정하지 않음.
+    /// 기본값은 사이트마다 공개 여부가 다른 연봉, 고용형태, 기술 스택을 판정하지 않음
+    pub min_hit_rates: Vec<(JobField, f64)>,
+    /// JSON 리포트를 저장할 경로. None이면 저장하지 않음
+    pub report_path: Option<PathBuf>,
+}
+
+impl Default for HealthConfig {
+    fn default() -> Self {
+        Self {
+            min_hit_rates: vec![
+                (JobField::Title, 0.95),
+                (JobField::Company, 0.95),
+                (JobField::Url, 0.95),
+                (JobField::ExperienceYears, 0.8),
+                (JobField::Deadline, 0.8),
+                (JobField::Location, 0.8),
+            ],
+            report_path: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FieldHealth {
+    pub field: &'static str,
+    pub hits: usize,
+    pub hit_rate: f64,
+    pub min_hit_rate: Option<f64>,
+    pub ok: bool,
+}
+
+/// 한 사이트 수집 결과의 상태 점검 리포트
+#[derive(Debug, Clone, Serialize)]
+pub struct HealthReport {
+    pub source: String,
+    pub crawl_date: NaiveDate,
+    pub job_count: usize,
+    pub pages: Vec<PageStat>,
+    pub fields: Vec<FieldHealth>,
+    pub issues: Vec<String>,
+    pub healthy: bool,
+}
+
+impl HealthReport {
+    pub fn new(
+        source: &str,
+        crawl_date: NaiveDate,
+        jobs: &[Job],
+        pages: Vec<PageStat>,
+        config: &HealthConfig,
+    ) -> Self {
+        let mut issues = Vec::new();
+
+        if jobs.is_empty() {
+            issues.push("수집된 공고 없음".to_string());
+        }
+        for stat in &pages {
+            match &stat.error {
+                Some(error) => issues.push(format!("페이지 {} 실패: {}", stat.page, error)),
+                None if stat.jobs == 0 => {
+                    issues.push(format!("페이지 {} 공고 0개: {}", stat.page, stat.url))
+                }
+                None => {}
+            }
+        }
+
+        let fields = JobField::ALL
+            .iter()
+            .map(|field| {
+                let hits = jobs.iter().filter(|job| field.is_present(job)).count();
+                let hit_rate = if jobs.is_empty() {
+                    0.0
+                } else {
+                    hits as f64 / jobs.len() as f64
+                };
+                let min_hit_rate = config
+                    .min_hit_rates
+                    .iter()
+                    .find(|(target, _)| target == field)
+                    .map(|(_, rate)| *rate);
+                // 공고가 없으면 위에서 이미 이상으로 기록했으므로 필드별 판정은 생략
+                let ok = jobs.is_empty() || min_hit_rate.is_none_or(|min| hit_rate >= min);
+
+                if !ok {
+                    issues.push(format!(
+                        "{} 추출률 {:.1}% (기준 {:.1}%)",
+                        field.name(),
+                        hit_rate * 100.0,
+                        min_hit_rate.unwrap_or_default() * 100.0
+                    ));
+                }
+
+                FieldHealth {
+                    field: field.name(),
+                    hits,
+                    hit_rate,
+                    min_hit_rate,
+                    ok,
+                }
+            })
+            .collect();
+
+        Self {
+            source: source.to_string(),
+            crawl_date,
+            job_count: jobs.len(),
+            pages,
+            fields,
+            healthy: issues.is_empty(),
+            issues,
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+
+    /// 결과를 출력하고 `report_path`가 있으면 JSON으로 저장
+    pub(crate) fn report(&self, report_path: Option<&Path>) {
+        if self.healthy {
+            println!(
+                "✅ {} 수집 상태 정상 (공고 {}개)",
+                self.source, self.job_count
+            );
+        } else {
+            eprintln!("❌ {} 수집 상태 이상", self.source);
+            for issue in &self.issues {
+                eprintln!("  - {}", issue);
+            }
+        }
+
+        if let Some(path) = report_path {
+            match std::fs::write(path, self.to_json()) {
+                Ok(_) => println!("✅ 상태 리포트 저장 완료: {}", path.display()),
+                Err(e) => eprintln!("❌ 상태 리포트 저장 실패 ({}): {}", path.display(), e),
+            }
+        }
+    }
+}
@@ -1,23 +1,46 @@
+pub mod cache;
 pub mod clients;
 pub mod crawler;
+pub mod description;
 pub mod enricher;
 pub mod enrichers;
+pub mod error;
+pub mod fetcher;
+pub mod fetchers;
+pub mod health;
 pub mod models;
+pub mod normalizers;
 pub mod pipeline;
 pub mod utils;
 pub mod writer;
 
+pub use cache::{CachedEnrichment, EnrichmentCache};
 pub use clients::{
-    SaraminClient, SaraminCrawlConfig, SaraminJobCategory, WantedClient, WantedCrawlConfig,
-    WantedJobCategory, WantedJobSubcategory,
+    AtsBoardClient, AtsBoardConfig, AtsFlavor, ConfigurableClient, ConfigurableCrawlConfig,
+    JobKoreaClient, JobKoreaCrawlConfig, JobKoreaDuty, JumpitClient, JumpitCrawlConfig,
+    JumpitJobCategory, JumpitLocation, ProgrammersClient, ProgrammersCrawlConfig,
+    ProgrammersJobCategory, RememberClient, RememberCrawlConfig, RocketpunchCareer,
+    RocketpunchClient, RocketpunchCrawlConfig, RocketpunchJobRole, SaraminCareer, SaraminClient,
+    SaraminCompanyType, SaraminCrawlConfig, SaraminEducation, SaraminEmploymentType,
+    SaraminJobCategory, SaraminRegion, SaraminSort, SiteDefinition, WantedClient,
+    WantedCrawlConfig, WantedCrawlMode, WantedJobCategory, WantedJobSort, WantedJobSubcategory,
+    WantedLocation,
 };
 pub use crawler::{
-    DetailCrawlConfig, JobCrawler, JobListInfiniteScrollCrawler, JobListPaginatedCrawler,
+    CrawlOutput, DEFAULT_MAX_PAGES, DetailCrawlConfig, JobCrawler, JobListInfiniteScrollCrawler,
+    JobListPaginatedCrawler, ListFilter,
 };
 pub use enricher::{EnricherConfig, JobEnricher};
 pub use enrichers::BlindEnricher;
-pub use models::Job;
+pub use error::Error;
+pub use fetcher::{FetcherBackend, FetcherKind, PageFetcher};
+pub use fetchers::{ChromeBackend, FixtureBackend, HttpBackend};
+pub use health::{HealthConfig, HealthReport, JobField};
+pub use models::{
+    CompanyAttribute, DeadlineKind, ExperienceRequirement, Job, JobDescription, Region, Salary,
+};
+pub use normalizers::RegionFilter;
 pub use pipeline::CrawlPipeline;
 pub use writer::save_to_csv;
 
-pub type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+pub type Result<T> = std::result::Result<T, Error>;
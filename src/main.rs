@@ -1,39 +1,86 @@
 use jd_crawler::{
-    BlindEnricher, DetailCrawlConfig, EnricherConfig, SaraminClient, SaraminCrawlConfig,
-    SaraminJobCategory,
+    BlindEnricher, DetailCrawlConfig, EnricherConfig, FetcherKind, SaraminClient,
+    SaraminCrawlConfig, SaraminJobCategory,
 };
-use jd_crawler::{CrawlPipeline, Result};
-use jd_crawler::{WantedClient, WantedCrawlConfig, WantedJobCategory, WantedJobSubcategory};
+use jd_crawler::{CrawlPipeline, HealthConfig, Result, health};
+use jd_crawler::{
+    WantedClient, WantedCrawlConfig, WantedCrawlMode, WantedJobCategory, WantedJobSort,
+    WantedJobSubcategory,
+};
+use std::process::ExitCode;
+use std::time::Duration;
 
-fn main() -> Result<()> {
-    CrawlPipeline::new()
+fn main() -> Result<ExitCode> {
+    let wanted = CrawlPipeline::new()
         .crawl(WantedClient::new(WantedCrawlConfig {
             category: WantedJobCategory::Development,
             subcategory: WantedJobSubcategory::Frontend,
+            sort: WantedJobSort::Recommend,
+            location_tags: vec![],
+            skill_tags: vec![],
             total_pages: 2,
             min_years: 0,
             max_years: 5,
             thread_count: 8,
             exclude_keywords: vec!["IOS", "안드로이드", "5년 이상"],
+            locations: vec![],
+            fetcher: FetcherKind::Chrome,
+            mode: WantedCrawlMode::Html,
         }))?
         .fetch_details(DetailCrawlConfig {
             thread_count: 8,
             includes: vec![],
+            delay: Duration::from_millis(500),
+        })
+        .check_health(HealthConfig {
+            report_path: Some("wanted.health.json".into()),
+            ..HealthConfig::default()
         })
         .save_and_then("wanted.csv")
-        .enrich(BlindEnricher::new(EnricherConfig { thread_count: 1 }))
+        .enrich(BlindEnricher::new(EnricherConfig {
+            thread_count: 1,
+            cache_path: Some("blind.cache.json".into()),
+            cache_ttl: Duration::from_secs(7 * 24 * 60 * 60),
+            fetcher: FetcherKind::Chrome,
+        }))
         .save("wanted.csv");
 
-    CrawlPipeline::new()
+    let saramin = CrawlPipeline::new()
         .crawl(SaraminClient::new(SaraminCrawlConfig {
             category: SaraminJobCategory::Frontend,
-            total_pages: 8,
+            careers: vec![],
+            min_years: None,
+            max_years: None,
+            education: None,
+            employment_types: vec![],
+            regions: vec![],
+            min_salary: None,
+            company_types: vec![],
+            sort: None,
+            total_pages: None,
             thread_count: 8,
             exclude_keywords: vec!["IOS", "안드로이드", "5년 이상"],
+            locations: vec![],
+            fetcher: FetcherKind::Http,
         }))?
+        .fetch_details(DetailCrawlConfig {
+            thread_count: 8,
+            includes: vec![],
+            delay: Duration::from_millis(500),
+        })
+        .check_health(HealthConfig {
+            report_path: Some("saramin.health.json".into()),
+            ..HealthConfig::default()
+        })
         .save_and_then("saramin.csv")
-        .enrich(BlindEnricher::new(EnricherConfig { thread_count: 1 }))
+        .enrich(BlindEnricher::new(EnricherConfig {
+            thread_count: 1,
+            cache_path: Some("blind.cache.json".into()),
+            cache_ttl: Duration::from_secs(7 * 24 * 60 * 60),
+            fetcher: FetcherKind::Http,
+        }))
         .save("saramin.csv");
 
-    Ok(())
+    // 수집 상태에 이상이 있으면 실패 코드로 종료해 스케줄러에서 바로 알 수 있게 함
+    Ok(health::exit_code(wanted.iter().chain(&saramin)))
 }
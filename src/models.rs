@@ -1,13 +1,174 @@
-use serde::Serialize;
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Default)]
 pub struct Job {
     pub title: String,
     pub company: String,
+    /// 사이트에서 부여한 회사 식별자 (원티드 API 모드 등)
+    pub company_id: Option<String>,
     pub experience_years: String,
+    pub experience: ExperienceRequirement,
     pub deadline: String,
+    pub deadline_date: Option<NaiveDate>,
+    pub deadline_kind: Option<DeadlineKind>,
     pub location: String,
-    pub rating: Option<String>,
-    pub review_count: Option<u32>,
+    pub region: Region,
+    pub salary: Option<Salary>,
+    pub employment_type: String,
+    /// 스타트업 성장 단계 (예: "성장기", "초기")
+    pub company_stage: Option<String>,
+    /// 투자 단계 (예: "시리즈 B")
+    pub investment_round: Option<String>,
+    /// 회사 규모 (예: "1,000명 이상", "대기업")
+    pub company_size: Option<String>,
+    pub tech_stack: Vec<String>,
+    pub description: JobDescription,
+    /// 보강 사이트에서 가져온 회사 정보 (평점, 리뷰 개수 등). 키마다 csv 컬럼 하나로 저장
+    pub company_attributes: Vec<CompanyAttribute>,
     pub url: String,
 }
+
+impl Job {
+    pub fn is_expired(&self, date: NaiveDate) -> bool {
+        self.deadline_date.is_some_and(|deadline| deadline < date)
+    }
+
+    pub fn attribute(&self, key: &str) -> Option<&str> {
+        self.company_attributes
+            .iter()
+            .find(|attribute| attribute.key == key)
+            .map(|attribute| attribute.value.as_str())
+    }
+
+    /// 같은 키의 값이 이미 있으면 새 값으로 교체
+    pub fn set_attribute(&mut self, attribute: CompanyAttribute) {
+        match self
+            .company_attributes
+            .iter_mut()
+            .find(|existing| existing.key == attribute.key)
+        {
+            Some(existing) => *existing = attribute,
+            None => self.company_attributes.push(attribute),
+        }
+    }
+}
+
+/// 보강 사이트에서 가져온 회사 정보 한 항목
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CompanyAttribute {
+    /// csv 컬럼 이름 (예: "rating", "review_count")
+    pub key: String,
+    pub value: String,
+    /// 값을 가져온 사이트 (예: "블라인드")
+    pub source: String,
+    pub fetched_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Default, PartialEq, Eq)]
+pub struct JobDescription {
+    /// 주요업무
+    pub main_tasks: String,
+    /// 자격요건
+    pub requirements: String,
+    /// 우대사항
+    pub preferred: String,
+    /// 혜택 및 복지
+    pub benefits: String,
+    /// 채용 절차
+    pub hiring_process: String,
+}
+
+impl JobDescription {
+    /// 기술 스택을 찾을 본문 (주요업무, 자격요건, 우대사항). 복지나 회사 소개의 단어는 제외
+    pub fn skill_text(&self) -> String {
+        [&self.main_tasks, &self.requirements, &self.preferred]
+            .into_iter()
+            .filter(|section| !section.is_empty())
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum DeadlineKind {
+    /// 마감일이 정해진 공고
+    Fixed,
+    /// 상시채용
+    Rolling,
+    /// 채용시 마감
+    UntilFilled,
+}
+
+#[derive(Debug, Clone, Serialize, Default, PartialEq, Eq)]
+pub struct ExperienceRequirement {
+    pub min_years: Option<u8>,
+    pub max_years: Option<u8>,
+    pub accepts_newcomer: bool,
+    /// 경력무관
+    pub any: bool,
+}
+
+impl ExperienceRequirement {
+    pub fn accepts(&self, years: u8) -> bool {
+        if self.any || (years == 0 && self.accepts_newcomer) {
+            return true;
+        }
+        self.min_years.is_none_or(|min| years >= min)
+            && self.max_years.is_none_or(|max| years <= max)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Default, PartialEq, Eq)]
+pub struct Region {
+    pub sido: Option<String>,
+    /// 일반구가 있는 시는 "성남시 분당구"처럼 시와 구를 함께 표기
+    pub sigungu: Option<String>,
+    pub dong: Option<String>,
+}
+
+impl Region {
+    /// `other`가 이 지역 범위 안에 있는지 여부. 시도 없이 시군구만 있으면 시도는 비교하지 않음
+    pub fn contains(&self, other: &Region) -> bool {
+        let same_sido = match &self.sido {
+            Some(_) => self.sido == other.sido,
+            None => self.sigungu.is_some(),
+        };
+        let same_sigungu = self.sigungu.as_ref().is_none_or(|sigungu| {
+            other.sigungu.as_ref().is_some_and(|other_sigungu| {
+                other_sigungu == sigungu
+                    || other_sigungu.starts_with(&format!("{} ", sigungu))
+                    || sigungu.starts_with(&format!("{} ", other_sigungu))
+            })
+        });
+        let same_dong = self
+            .dong
+            .as_ref()
+            .is_none_or(|dong| other.dong.as_ref() == Some(dong));
+
+        same_sido && same_sigungu && same_dong
+    }
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub enum Salary {
+    /// 연봉 범위 (만원)
+    Range { min: Option<u32>, max: Option<u32> },
+    /// 회사내규에 따름, 협의, 면접 후 결정
+    Negotiable,
+}
+
+impl Salary {
+    /// 연봉 상한(없으면 하한)이 `min` 만원 이상이거나 협의 가능한 경우
+    pub fn meets(&self, min: u32) -> bool {
+        match self {
+            Self::Range {
+                min: low,
+                max: high,
+            } => high.or(*low).is_none_or(|amount| amount >= min),
+            Self::Negotiable => true,
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use crate::models::DeadlineKind;
+use chrono::{Datelike, Days, NaiveDate};
+use regex::Regex;
+
+/// "~10/31(금)", "오늘마감", "내일마감", "D-3", "2025.11.30", "상시채용", "채용시" 등을
+/// 수집일(`crawl_date`) 기준의 날짜와 마감 유형으로 변환
+pub fn parse_deadline(
+    text: &str,
+    crawl_date: NaiveDate,
+) -> (Option<NaiveDate>, Option<DeadlineKind>) {
+    let text = text.trim();
+
+    if text.contains("상시") {
+        return (None, Some(DeadlineKind::Rolling));
+    }
+    if text.contains("채용시") {
+        return (None, Some(DeadlineKind::UntilFilled));
+    }
+
+    let date = parse_relative_date(text, crawl_date)
+        .or_else(|| parse_full_date(text))
+        .or_else(|| parse_month_day(text, crawl_date));
+
+    match date {
+        Some(date) => (Some(date), Some(DeadlineKind::Fixed)),
+        None => (None, None),
+    }
+}
+
+fn parse_relative_date(text: &str, crawl_date: NaiveDate) -> Option<NaiveDate> {
+    if text.contains("오늘") {
+        return Some(crawl_date);
+    }
+    if text.contains("내일") {
+        return crawl_date.checked_add_days(Days::new(1));
+    }
+
+    let re = Regex::new(r"(?i)D\s*-\s*(\d+|day)").unwrap();
+    let captures = re.captures(text)?;
+    let days = captures[1].parse().unwrap_or(0);
+    crawl_date.checked_add_days(Days::new(days))
+}
+
+fn parse_full_date(text: &str) -> Option<NaiveDate> {
+    let re = Regex::new(r"(\d{2,4})[./-]\s*(\d{1,2})[./-]\s*(\d{1,2})").unwrap();
+    let captures = re.captures(text)?;
+    let year: i32 = captures[1].parse().ok()?;
+    let year = if year < 100 { 2000 + year } else { year };
+    NaiveDate::from_ymd_opt(year, captures[2].parse().ok()?, captures[3].parse().ok()?)
+}
+
+fn parse_month_day(text: &str, crawl_date: NaiveDate) -> Option<NaiveDate> {
+    let re = Regex::new(r"(\d{1,2})[./](\d{1,2})").unwrap();
+    let captures = re.captures(text)?;
+    let month: u32 = captures[1].parse().ok()?;
+    let day: u32 = captures[2].parse().ok()?;
+
+    // 연도가 없으므로 수집일과 가장 가까운 연도로 추정 (12월 수집 시 "~01/05"는 다음 해)
+    [
+        crawl_date.year() - 1,
+        crawl_date.year(),
+        crawl_date.year() + 1,
+    ]
+    .into_iter()
+    .filter_map(|year| NaiveDate::from_ymd_opt(year, month, day))
+    .min_by_key(|date| (*date - crawl_date).num_days().abs())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> Option<NaiveDate> {
+        NaiveDate::from_ymd_opt(year, month, day)
+    }
+
+    #[test]
+    fn parses_deadline_texts() {
+        let crawl_date = date(2025, 12, 20).unwrap();
+        let fixed = Some(DeadlineKind::Fixed);
+        let cases = [
+            ("~12/31(수)", (date(2025, 12, 31), fixed)),
+            ("~01/05(월)", (date(2026, 1, 5), fixed)),
+            ("~11.30", (date(2025, 11, 30), fixed)),
+            ("2026.01.15", (date(2026, 1, 15), fixed)),
+            ("2026-02-01 23:59", (date(2026, 2, 1), fixed)),
+            ("26.01.10", (date(2026, 1, 10), fixed)),
+            ("오늘마감", (date(2025, 12, 20), fixed)),
+            ("내일마감", (date(2025, 12, 21), fixed)),
+            ("D-3", (date(2025, 12, 23), fixed)),
+            ("D-day", (date(2025, 12, 20), fixed)),
+            ("상시채용", (None, Some(DeadlineKind::Rolling))),
+            ("채용시 마감", (None, Some(DeadlineKind::UntilFilled))),
+            ("~02/30", (None, None)),
+            ("", (None, None)),
+        ];
+
+        for (text, expected) in cases {
+            assert_eq!(parse_deadline(text, crawl_date), expected, "{}", text);
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use crate::models::ExperienceRequirement;
+use regex::Regex;
+
+/// "경력 3-5년", "신입·경력", "경력무관", "3년 이상", "경력 2년↑" 등 사이트별 경력 표기를 해석
+pub fn parse_experience(text: &str) -> ExperienceRequirement {
+    let text = text.trim();
+
+    if text.contains("무관") {
+        return ExperienceRequirement {
+            accepts_newcomer: true,
+            any: true,
+            ..Default::default()
+        };
+    }
+
+    let re = Regex::new(r"(\d+)\s*년?").unwrap();
+    let accepts_newcomer = text.contains("신입");
+    let is_lower_bound = text.contains("이상") || text.contains('↑');
+    let is_upper_bound = text.contains("이하") || text.contains('↓');
+    let years: Vec<u8> = re
+        .captures_iter(text)
+        .filter_map(|captures| captures[1].parse().ok())
+        .collect();
+
+    let newcomer_min = accepts_newcomer.then_some(0);
+    let (min_years, max_years) = match years.as_slice() {
+        [min, max, ..] => (Some(*min.min(max)), Some(*min.max(max))),
+        [years] if is_upper_bound || (accepts_newcomer && !is_lower_bound) => {
+            (newcomer_min, Some(*years))
+        }
+        [years] => (Some(*years), None),
+        [] if accepts_newcomer && !text.contains("경력") => (Some(0), Some(0)),
+        [] => (newcomer_min, None),
+    };
+
+    ExperienceRequirement {
+        min_years,
+        max_years,
+        accepts_newcomer,
+        any: false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn requirement(min: Option<u8>, max: Option<u8>, newcomer: bool) -> ExperienceRequirement {
+        ExperienceRequirement {
+            min_years: min,
+            max_years: max,
+            accepts_newcomer: newcomer,
+            any: false,
+        }
+    }
+
+    #[test]
+    fn parses_experience_texts() {
+        let cases = [
+            ("경력 3-5년", requirement(Some(3), Some(5), false)),
+            ("경력 5~3년", requirement(Some(3), Some(5), false)),
+            ("3년 이상", requirement(Some(3), None, false)),
+            ("경력 2년↑", requirement(Some(2), None, false)),
+            ("경력 7년 이하", requirement(None, Some(7), false)),
+            ("신입", requirement(Some(0), Some(0), true)),
+            ("신입·경력", requirement(Some(0), None, true)),
+            ("신입~3년", requirement(Some(0), Some(3), true)),
+            ("신입·경력 2년↑", requirement(Some(2), None, true)),
+            ("경력", requirement(None, None, false)),
+            (
+                "경력무관",
+                ExperienceRequirement {
+                    accepts_newcomer: true,
+                    any: true,
+                    ..Default::default()
+                },
+            ),
+        ];
+
+        for (text, expected) in cases {
+            assert_eq!(parse_experience(text), expected, "{}", text);
+        }
+    }
+}
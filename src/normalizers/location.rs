@@ -0,0 +1,262 @@
+use crate::models::Region;
+use std::sync::LazyLock;
+
+const REGIONS: &str = include_str!("../../data/regions.txt");
+
+/// 시군구 표기가 없는 지역명. 동 단위로 구분할 수 없는 목록 데이터를 위해 시군구로 대응
+const LANDMARKS: [(&str, &str); 10] = [
+    ("판교", "경기 성남시 분당구"),
+    ("광교", "경기 수원시 영통구"),
+    ("테헤란로", "서울 강남구"),
+    ("여의도", "서울 영등포구"),
+    ("구로디지털단지", "서울 구로구"),
+    ("가산디지털단지", "서울 금천구"),
+    ("마곡", "서울 강서구"),
+    ("상암", "서울 마포구"),
+    ("성수", "서울 성동구"),
+    ("송도", "인천 연수구"),
+];
+
+struct Sido {
+    name: &'static str,
+    aliases: Vec<&'static str>,
+    sigungu: Vec<&'static str>,
+}
+
+static SIDO_TABLE: LazyLock<Vec<Sido>> = LazyLock::new(|| {
+    REGIONS
+        .lines()
+        .filter(|line| !line.starts_with('#') && !line.trim().is_empty())
+        .map(|line| {
+            let mut columns = line.split('\t');
+            let split = |column: Option<&'static str>| {
+                column
+                    .unwrap_or_default()
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|s| !s.is_empty())
+                    .collect()
+            };
+            Sido {
+                name: columns.next().unwrap_or_default().trim(),
+                aliases: split(columns.next()),
+                sigungu: split(columns.next()),
+            }
+        })
+        .collect()
+});
+
+/// "서울 강남구", "서울특별시 강남구 역삼동", "경기 성남시 분당구", "판교" 등을 시도/시군구/동으로 분리
+pub fn parse_region(text: &str) -> Region {
+    let tokens: Vec<&str> = text
+        .split(|c: char| c.is_whitespace() || ",·∙>/".contains(c))
+        .filter(|token| !token.is_empty())
+        .collect();
+
+    let Some(first) = tokens.first() else {
+        return Region::default();
+    };
+
+    let (sido, rest) = match find_sido(first) {
+        Some(sido) => (sido, &tokens[1..]),
+        None => match find_sido_by_sigungu(first) {
+            Some(sido) => (sido, &tokens[..]),
+            None => {
+                if let Some(sigungu) = find_ambiguous_sigungu(first) {
+                    return Region {
+                        sido: None,
+                        sigungu: Some(sigungu.to_string()),
+                        dong: parse_dong(tokens.get(1)),
+                    };
+                }
+                return LANDMARKS
+                    .iter()
+                    .find(|(landmark, _)| text.contains(landmark))
+                    .map(|(_, address)| parse_region(address))
+                    .unwrap_or_default();
+            }
+        },
+    };
+
+    let mut rest = rest.iter();
+    let mut sigungu = rest
+        .next()
+        .and_then(|token| find_sigungu(sido, token))
+        .map(str::to_string);
+
+    let mut next = rest.next();
+    if let (Some(city), Some(gu)) = (&sigungu, next)
+        && city.ends_with('시')
+        && gu.ends_with('구')
+    {
+        sigungu = Some(format!("{} {}", city, gu));
+        next = rest.next();
+    }
+
+    Region {
+        sido: Some(sido.name.to_string()),
+        sigungu,
+        dong: parse_dong(next),
+    }
+}
+
+fn parse_dong(token: Option<&&str>) -> Option<String> {
+    token
+        .filter(|token| token.ends_with(['동', '읍', '면', '가']))
+        .map(|token| token.to_string())
+}
+
+fn find_sido(token: &str) -> Option<&'static Sido> {
+    SIDO_TABLE
+        .iter()
+        .find(|sido| sido.name == token || sido.aliases.contains(&token))
+}
+
+fn find_sido_by_sigungu(token: &str) -> Option<&'static Sido> {
+    let mut candidates = SIDO_TABLE
+        .iter()
+        .filter(|sido| find_sigungu(sido, token).is_some());
+    let sido = candidates.next()?;
+    candidates.next().is_none().then_some(sido)
+}
+
+/// "중구", "강서구"처럼 여러 시도에 있어 시도를 정할 수 없는 시군구
+fn find_ambiguous_sigungu(token: &str) -> Option<&'static str> {
+    SIDO_TABLE.iter().find_map(|sido| find_sigungu(sido, token))
+}
+
+fn find_sigungu(sido: &Sido, token: &str) -> Option<&'static str> {
+    sido.sigungu.iter().copied().find(|sigungu| {
+        *sigungu == token
+            || (sigungu.len() > token.len()
+                && sigungu.starts_with(token)
+                && sigungu[token.len()..].chars().count() == 1)
+    })
+}
+
+/// 지역 조건 목록. "서울 강남·서초"처럼 `·`로 묶인 조건은 각각의 조건으로 펼침.
+/// 시도 없이 "중구"만 지정하면 모든 시도의 중구와 일치
+#[derive(Debug, Clone, Default)]
+pub struct RegionFilter {
+    patterns: Vec<Region>,
+}
+
+impl RegionFilter {
+    pub fn new(patterns: &[&str]) -> Self {
+        let patterns = patterns
+            .iter()
+            .flat_map(|pattern| expand_pattern(pattern))
+            .map(|pattern| parse_region(&pattern))
+            .filter(|region| region.sido.is_some() || region.sigungu.is_some())
+            .collect();
+        Self { patterns }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.patterns.is_empty()
+    }
+
+    pub fn matches(&self, region: &Region) -> bool {
+        self.is_empty() || self.patterns.iter().any(|pattern| pattern.contains(region))
+    }
+}
+
+fn expand_pattern(pattern: &str) -> Vec<String> {
+    pattern
+        .split_whitespace()
+        .fold(vec![String::new()], |prefixes, token| {
+            prefixes
+                .iter()
+                .flat_map(|prefix| {
+                    token
+                        .split(['·', ','])
+                        .filter(|alternative| !alternative.is_empty())
+                        .map(move |alternative| format!("{} {}", prefix, alternative))
+                })
+                .collect()
+        })
+        .into_iter()
+        .map(|pattern| pattern.trim().to_string())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn region(sido: Option<&str>, sigungu: Option<&str>, dong: Option<&str>) -> Region {
+        Region {
+            sido: sido.map(str::to_string),
+            sigungu: sigungu.map(str::to_string),
+            dong: dong.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn parses_region_texts() {
+        let cases = [
+            ("서울 강남구", region(Some("서울"), Some("강남구"), None)),
+            (
+                "서울특별시 강남구 역삼동",
+                region(Some("서울"), Some("강남구"), Some("역삼동")),
+            ),
+            (
+                "경기 성남시 분당구 삼평동",
+                region(Some("경기"), Some("성남시 분당구"), Some("삼평동")),
+            ),
+            ("서울 강남", region(Some("서울"), Some("강남구"), None)),
+            ("부산광역시", region(Some("부산"), None, None)),
+            (
+                "마포구 합정동",
+                region(Some("서울"), Some("마포구"), Some("합정동")),
+            ),
+            ("판교", region(Some("경기"), Some("성남시 분당구"), None)),
+            (
+                "서울 > 영등포구",
+                region(Some("서울"), Some("영등포구"), None),
+            ),
+            // 여러 시도에 있는 시군구는 시도를 비워 둠
+            ("중구", region(None, Some("중구"), None)),
+            (
+                "중구 을지로동",
+                region(None, Some("중구"), Some("을지로동")),
+            ),
+            ("서울 중구", region(Some("서울"), Some("중구"), None)),
+            ("재택근무", Region::default()),
+            ("", Region::default()),
+        ];
+
+        for (text, expected) in cases {
+            assert_eq!(parse_region(text), expected, "{}", text);
+        }
+    }
+
+    #[test]
+    fn matches_region_filters() {
+        let cases = [
+            (vec![], "부산 해운대구", true),
+            (vec!["서울 강남·서초"], "서울 서초구 서초동", true),
+            (vec!["서울 강남·서초"], "서울 마포구", false),
+            (vec!["서울"], "서울특별시 마포구", true),
+            (vec!["판교"], "경기 성남시 분당구 삼평동", true),
+            (vec!["경기 성남시"], "경기 성남시 분당구", true),
+            (vec!["서울", "판교"], "경기 수원시 영통구", false),
+            (vec!["중구"], "서울 중구", true),
+            (vec!["중구"], "대구 중구", true),
+            (vec!["중구"], "서울 종로구", false),
+            (vec!["서울 중구"], "중구", false),
+            (vec!["서울 중구"], "부산 중구", false),
+        ];
+
+        for (patterns, text, expected) in cases {
+            let filter = RegionFilter::new(&patterns);
+            assert_eq!(
+                filter.matches(&parse_region(text)),
+                expected,
+                "{:?} / {}",
+                patterns,
+                text
+            );
+        }
+    }
+}
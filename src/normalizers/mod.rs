@@ -0,0 +1,11 @@
+pub mod deadline;
+pub mod experience;
+pub mod location;
+pub mod salary;
+pub mod tech_stack;
+
+pub use deadline::parse_deadline;
+pub use experience::parse_experience;
+pub use location::{RegionFilter, parse_region};
+pub use salary::{find_salary, parse_salary};
+pub use tech_stack::{find_tech_stack, merge_tech_stack, normalize_tech, normalize_tech_tags};
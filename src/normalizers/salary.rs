@@ -0,0 +1,179 @@
+use crate::models::Salary;
+use regex::Regex;
+
+const NEGOTIABLE_MARKERS: [&str; 4] = ["내규", "협의", "면접 후", "면접후"];
+
+/// 목록 카드의 조건 항목 중 급여 항목으로 볼 단어
+const SALARY_KEYWORDS: [&str; 6] = ["연봉", "월급", "만원", "내규", "협의", "면접"];
+
+/// 근무지·경력 등이 섞인 조건 항목들에서 급여 항목을 골라 해석
+pub fn find_salary(texts: impl IntoIterator<Item = String>) -> Option<Salary> {
+    texts
+        .into_iter()
+        .filter(|text| SALARY_KEYWORDS.iter().any(|keyword| text.contains(keyword)))
+        .find_map(|text| parse_salary(&text))
+}
+
+/// "연봉 3,000~4,000만원", "4,500만원 이상", "1억 2,000만원", "회사내규에 따름" 등을 만원 단위로 해석
+pub fn parse_salary(text: &str) -> Option<Salary> {
+    let text = text.trim();
+
+    if text.contains("시급") {
+        return None;
+    }
+
+    let mut parts = text.splitn(2, ['~', '–']);
+    let first_text = parts.next().unwrap_or_default();
+    let second = parts.next().and_then(|text| parse_amount(text, None));
+    // "3,000~4,000만원"처럼 단위가 뒤쪽에만 있으면 앞쪽 숫자도 같은 단위로 해석
+    let first = parse_amount(first_text, second.map(|(_, unit)| unit));
+    let second = second.map(|(amount, _)| amount);
+    let first = first.map(|(amount, _)| amount);
+
+    let multiplier = if is_monthly(text) { 12 } else { 1 };
+    let yearly = |amount: Option<u32>| amount.and_then(|amount| amount.checked_mul(multiplier));
+
+    let (min, max) = match (first, second) {
+        (Some(min), Some(max)) => (Some(min), Some(max)),
+        (None, Some(max)) => (None, Some(max)),
+        (Some(amount), None) if text.contains("이하") || text.contains("최대") => {
+            (None, Some(amount))
+        }
+        (Some(amount), None) if text.contains("이상") || text.contains("최소") => {
+            (Some(amount), None)
+        }
+        (Some(amount), None) => (Some(amount), Some(amount)),
+        (None, None) => (None, None),
+    };
+
+    let (min, max) = (yearly(min), yearly(max));
+    if min.is_some() || max.is_some() {
+        return Some(Salary::Range { min, max });
+    }
+
+    NEGOTIABLE_MARKERS
+        .iter()
+        .any(|marker| text.contains(marker))
+        .then_some(Salary::Negotiable)
+}
+
+/// "월급" 또는 단어로 쓰인 "월" ("월 300만원", "월300만원")만 월 단위로 간주 ("3개월"은 제외)
+fn is_monthly(text: &str) -> bool {
+    let re = Regex::new(r"(?:^|[^\p{L}])월(?:[^\p{L}]|$)").unwrap();
+    text.contains("월급") || re.is_match(text)
+}
+
+/// 금액 단위 (만원 환산 배수)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Unit {
+    Eok,
+    CheonMan,
+    Man,
+    Won,
+}
+
+impl Unit {
+    fn parse(unit: &str) -> Option<Self> {
+        match unit {
+            "억" => Some(Self::Eok),
+            "천만" => Some(Self::CheonMan),
+            "만" => Some(Self::Man),
+            "원" => Some(Self::Won),
+            _ => None,
+        }
+    }
+
+    fn to_man(self, number: u32) -> Option<u32> {
+        match self {
+            Self::Eok => number.checked_mul(10000),
+            Self::CheonMan => number.checked_mul(1000),
+            Self::Man => Some(number),
+            Self::Won => Some(number / 10000),
+        }
+    }
+}
+
+/// 한 구간에서 처음 나오는 금액을 만원 단위로 계산 ("1억 2,000만" → 12000, "50,000,000원" → 5000).
+/// 단위(억/천만/만/원)가 붙은 숫자만 금액으로 보고, 단위 없는 숫자는 `default_unit`이 있을 때만 사용
+fn parse_amount(text: &str, default_unit: Option<Unit>) -> Option<(u32, Unit)> {
+    let re = Regex::new(r"(\d[\d,]*)\s*(억|천만|천|만|원)?").unwrap();
+    let mut tokens = re.captures_iter(text).filter_map(|captures| {
+        let number: u32 = captures[1].replace(',', "").parse().ok()?;
+        let unit = captures.get(2).map(|unit| unit.as_str());
+        Some((number, unit, captures.get(0)?.range()))
+    });
+
+    while let Some((number, unit, range)) = tokens.next() {
+        match unit {
+            Some("억") => {
+                let mut amount = Unit::Eok.to_man(number)?;
+                // "1억 2,000만", "1억 2천"처럼 바로 이어지는 천만/만 단위를 더함
+                if let Some((rest, rest_unit, rest_range)) = tokens.next()
+                    && text[range.end..rest_range.start].trim().is_empty()
+                {
+                    let rest = match rest_unit {
+                        Some("천") | Some("천만") => rest.checked_mul(1000),
+                        Some("만") => Some(rest),
+                        _ => None,
+                    };
+                    if let Some(rest) = rest {
+                        amount = amount.checked_add(rest)?;
+                    }
+                }
+                return Some((amount, Unit::Eok));
+            }
+            Some(unit) => {
+                if let Some(unit) = Unit::parse(unit) {
+                    return Some((unit.to_man(number)?, unit));
+                }
+            }
+            None => {
+                if let Some(unit) = default_unit {
+                    return Some((unit.to_man(number)?, unit));
+                }
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn range(min: Option<u32>, max: Option<u32>) -> Option<Salary> {
+        Some(Salary::Range { min, max })
+    }
+
+    #[test]
+    fn parses_salary_texts() {
+        let cases = [
+            ("연봉 3,000~4,000만원", range(Some(3000), Some(4000))),
+            ("4,500만원 이상", range(Some(4500), None)),
+            ("최대 6000만원", range(None, Some(6000))),
+            ("1억 2,000만원", range(Some(12000), Some(12000))),
+            ("1억 2천만원", range(Some(12000), Some(12000))),
+            ("5천만원", range(Some(5000), Some(5000))),
+            ("월 300만원", range(Some(3600), Some(3600))),
+            ("월급 250만원", range(Some(3000), Some(3000))),
+            ("연봉 50,000,000원 이상", range(Some(5000), None)),
+            (
+                "연봉 4000만원 (인센티브 200%)",
+                range(Some(4000), Some(4000)),
+            ),
+            ("연봉 4000만원, 3개월 수습", range(Some(4000), Some(4000))),
+            ("회사내규에 따름", Some(Salary::Negotiable)),
+            ("면접 후 결정", Some(Salary::Negotiable)),
+            ("연봉 협상 후 3개월 수습", None),
+            ("연봉 상위 10% 보장", None),
+            ("시급 10,000원", None),
+            ("연봉 99999999999원", None),
+            ("월 99999999억", None),
+        ];
+
+        for (text, expected) in cases {
+            assert_eq!(parse_salary(text), expected, "{}", text);
+        }
+    }
+}
@@ -0,0 +1,163 @@
+use std::cmp::Reverse;
+use std::sync::LazyLock;
+
+const TECH_STACK: &str = include_str!("../../data/tech_stack.txt");
+
+struct Tech {
+    name: &'static str,
+    aliases: Vec<String>,
+}
+
+static TECH_TABLE: LazyLock<Vec<Tech>> = LazyLock::new(|| {
+    TECH_STACK
+        .lines()
+        .filter(|line| !line.starts_with('#') && !line.trim().is_empty())
+        .map(|line| {
+            let (name, aliases) = line.split_once('\t').unwrap_or((line, ""));
+            let name = name.trim();
+            let aliases = aliases
+                .split(',')
+                .map(|alias| alias.trim().to_lowercase())
+                .filter(|alias| !alias.is_empty())
+                .collect();
+            Tech { name, aliases }
+        })
+        .collect()
+});
+
+/// "리액트", "ReactJS" 같은 표기를 사전의 표준 이름("React")으로 변환
+pub fn normalize_tech(text: &str) -> Option<&'static str> {
+    let text = text.trim().to_lowercase();
+    TECH_TABLE
+        .iter()
+        .find(|tech| tech.name.to_lowercase() == text || tech.aliases.contains(&text))
+        .map(|tech| tech.name)
+}
+
+/// 본문에서 사전에 있는 기술 이름을 찾아 등장 순서대로 표준 이름 목록으로 반환.
+/// 표기가 겹치면 가장 긴 표기만 사용 ("Spring Boot" 안의 "Spring"은 따로 세지 않음)
+pub fn find_tech_stack(text: &str) -> Vec<String> {
+    let text = &text.to_lowercase();
+    let mut matches: Vec<(usize, usize, &'static str)> = TECH_TABLE
+        .iter()
+        .flat_map(|tech| {
+            tech.aliases.iter().flat_map(move |alias| {
+                find_words(text, alias).map(move |start| (start, start + alias.len(), tech.name))
+            })
+        })
+        .collect();
+    matches.sort_by_key(|&(start, end, _)| (start, Reverse(end)));
+
+    let mut found: Vec<String> = Vec::new();
+    let mut covered = 0;
+    for (start, end, name) in matches {
+        if start < covered {
+            continue;
+        }
+        covered = end;
+        if !found.iter().any(|tech| tech == name) {
+            found.push(name.to_string());
+        }
+    }
+    found
+}
+
+/// 영문/숫자가 앞뒤로 붙지 않은 위치에서만 일치로 판단
+/// ("java"가 "javascript"에, "js"가 "next.js"에 걸리지 않도록)
+fn find_words<'a>(text: &'a str, word: &'a str) -> impl Iterator<Item = usize> + 'a {
+    text.match_indices(word)
+        .map(|(index, _)| index)
+        .filter(move |&index| {
+            let before = text[..index].chars().next_back();
+            let mut after = text[index + word.len()..].chars();
+            let joined_before = before.is_some_and(|c| is_word_char(c) || c == '.');
+            let joined_after = match after.next() {
+                Some('.') => after.next().is_some_and(is_word_char),
+                Some(c) => is_word_char(c),
+                None => false,
+            };
+            !joined_before && !joined_after
+        })
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '+' || c == '#'
+}
+
+/// 기술스택 태그 목록을 표준 이름으로 변환. 사전에 있는 태그는 표준 이름이나 표기가 그대로
+/// 일치하면 받아들이고 ("Emotion", "Git"), 문장처럼 긴 항목은 본문과 같은 방식으로 찾음
+pub fn normalize_tech_tags(tags: impl IntoIterator<Item = impl AsRef<str>>) -> Vec<String> {
+    let mut tech_stack = Vec::new();
+    for tag in tags {
+        let tag = tag.as_ref();
+        match normalize_tech(tag) {
+            Some(tech) => merge_tech_stack(&mut tech_stack, [tech.to_string()]),
+            None => merge_tech_stack(&mut tech_stack, find_tech_stack(tag)),
+        }
+    }
+    tech_stack
+}
+
+/// 표준 이름 기준으로 중복을 제거하며 목록을 합침
+pub fn merge_tech_stack(base: &mut Vec<String>, other: impl IntoIterator<Item = String>) {
+    for tech in other {
+        if !base.contains(&tech) {
+            base.push(tech);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_tech_stack_in_text() {
+        let cases = [
+            (
+                "React와 TypeScript 기반 웹 서비스 개발",
+                vec!["React", "TypeScript"],
+            ),
+            (
+                "리액트, 타입스크립트 사용 경험",
+                vec!["React", "TypeScript"],
+            ),
+            ("Java 또는 Kotlin 기반 서버 개발", vec!["Java", "Kotlin"]),
+            // 단어 일부로 들어간 표기는 찾지 않음
+            ("JavaScript, Next.js 경험", vec!["JavaScript", "Next.js"]),
+            ("C++, C# 개발 경험", vec!["C++", "C#"]),
+            ("Node.js 서버 운영", vec!["Node.js"]),
+            ("파이썬 데이터 분석", vec!["Python"]),
+            // 겹치는 표기는 가장 긴 것만 사용
+            ("Spring Boot 기반 API 개발", vec!["Spring Boot"]),
+            ("Spring Boot, Spring 경험", vec!["Spring Boot", "Spring"]),
+            ("React Native 앱 개발", vec!["React Native"]),
+            ("React, React Native 경험", vec!["React", "React Native"]),
+            // 다른 뜻으로도 쓰이는 짧은 표기는 찾지 않음
+            ("JS, TS 및 git 사용", vec![]),
+            ("express delivery", vec![]),
+            ("Apollo Client 사용 경험", vec!["GraphQL"]),
+            ("문서 작성 능력", vec![]),
+        ];
+
+        for (text, expected) in cases {
+            assert_eq!(find_tech_stack(text), expected, "{}", text);
+        }
+    }
+
+    #[test]
+    fn normalizes_tech_names() {
+        assert_eq!(normalize_tech("ReactJS"), Some("React"));
+        assert_eq!(normalize_tech(" 쿠버네티스 "), Some("Kubernetes"));
+        assert_eq!(normalize_tech("spring boot"), Some("Spring Boot"));
+        assert_eq!(normalize_tech("엑셀"), None);
+    }
+
+    #[test]
+    fn normalizes_tech_tags() {
+        assert_eq!(
+            normalize_tech_tags(["React", "Emotion", "Git", "AWS Lambda", "Figma"]),
+            ["React", "Emotion", "Git", "AWS"]
+        );
+    }
+}
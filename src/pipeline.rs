@@ -1,27 +1,51 @@
-use crate::crawler::{DetailCrawlConfig, DetailCrawler};
+use crate::crawler::{CrawlOutput, DetailCrawlConfig, DetailCrawler, JobCrawler};
 use crate::enricher::JobEnricher;
+use crate::health::{HealthConfig, HealthReport, PageStat};
+use crate::normalizers::{RegionFilter, parse_deadline};
 use crate::writer::save_to_csv;
 use crate::{Job, Result};
+use chrono::{Local, NaiveDate};
 
-pub struct CrawlPipeline;
+pub struct CrawlPipeline {
+    crawl_date: NaiveDate,
+}
 
 #[must_use = "pipeline must end with .save() to execute"]
 pub struct PipelineWithJobs<C> {
     jobs: Vec<Job>,
     client: C,
+    crawl_date: NaiveDate,
+    pages: Vec<PageStat>,
+    health: Option<HealthReport>,
 }
 
 impl CrawlPipeline {
     pub fn new() -> Self {
-        Self
+        Self {
+            crawl_date: Local::now().date_naive(),
+        }
+    }
+
+    /// "D-3", "내일마감"처럼 상대적인 마감일을 계산할 기준일 지정
+    pub fn with_crawl_date(mut self, crawl_date: NaiveDate) -> Self {
+        self.crawl_date = crawl_date;
+        self
     }
 
     pub fn crawl<C>(self, client: C) -> Result<PipelineWithJobs<C>>
     where
         C: Crawler,
     {
-        let jobs = client.start_crawl()?;
-        Ok(PipelineWithJobs { jobs, client })
+        let CrawlOutput { jobs, pages } = client.start_crawl()?;
+        let mut pipeline = PipelineWithJobs {
+            jobs,
+            client,
+            crawl_date: self.crawl_date,
+            pages,
+            health: None,
+        };
+        pipeline.normalize_deadlines();
+        Ok(pipeline)
     }
 }
 
@@ -31,11 +55,68 @@ impl Default for CrawlPipeline {
     }
 }
 
-pub trait Crawler {
-    fn start_crawl(&self) -> Result<Vec<Job>>;
+pub trait Crawler: JobCrawler {
+    fn start_crawl(&self) -> Result<CrawlOutput>;
 }
 
-impl<C> PipelineWithJobs<C> {
+impl<C: JobCrawler> PipelineWithJobs<C> {
+    fn normalize_deadlines(&mut self) {
+        for job in &mut self.jobs {
+            (job.deadline_date, job.deadline_kind) = parse_deadline(&job.deadline, self.crawl_date);
+        }
+    }
+
+    pub fn drop_expired(mut self) -> Self {
+        let crawl_date = self.crawl_date;
+        self.jobs.retain(|job| !job.is_expired(crawl_date));
+        self
+    }
+
+    /// 마감일이 빠른 순으로 정렬하고 상시채용 등 날짜가 없는 공고는 뒤로 보냄
+    pub fn sort_by_deadline(mut self) -> Self {
+        self.jobs
+            .sort_by_key(|job| (job.deadline_date.is_none(), job.deadline_date));
+        self
+    }
+
+    pub fn filter_locations(mut self, locations: &[&str]) -> Self {
+        let region_filter = RegionFilter::new(locations);
+        self.jobs.retain(|job| region_filter.matches(&job.region));
+        self
+    }
+
+    /// 연봉 정보가 있고 `min` 만원에 못 미치는 공고 제외 (협의/미기재 공고는 유지)
+    pub fn filter_min_salary(mut self, min: u32) -> Self {
+        self.jobs
+            .retain(|job| job.salary.as_ref().is_none_or(|salary| salary.meets(min)));
+        self
+    }
+
+    /// 조건을 만족하는 공고만 남김 (예: `.filter(|job| job.experience.accepts_newcomer)`)
+    pub fn filter(mut self, predicate: impl Fn(&Job) -> bool) -> Self {
+        self.jobs.retain(|job| predicate(job));
+        self
+    }
+
+    /// 필드별 추출률과 공고가 0개인 목록 페이지를 점검한 리포트
+    pub fn health_report(&self, config: &HealthConfig) -> HealthReport {
+        HealthReport::new(
+            self.client.site_name(),
+            self.crawl_date,
+            &self.jobs,
+            self.pages.clone(),
+            config,
+        )
+    }
+
+    /// 상태를 점검해 출력하고 리포트를 저장. 리포트는 `save`가 돌려줌
+    pub fn check_health(mut self, config: HealthConfig) -> Self {
+        let report = self.health_report(&config);
+        report.report(config.report_path.as_deref());
+        self.health = Some(report);
+        self
+    }
+
     pub fn enrich(mut self, enricher: impl JobEnricher + 'static) -> Self {
         if let Ok(enriched) = enricher.start_enrich(&self.jobs) {
             self.jobs = enriched
@@ -53,12 +134,14 @@ impl<C> PipelineWithJobs<C> {
         self
     }
 
-    pub fn save(self, path: impl Into<String>) {
+    /// csv를 저장하고 `check_health`로 점검한 리포트를 돌려줌 (점검하지 않았으면 None)
+    pub fn save(self, path: impl Into<String>) -> Option<HealthReport> {
         let path = path.into();
         match save_to_csv(&self.jobs, &path) {
             Ok(_) => println!("✅ csv 저장 완료: {}", path),
             Err(e) => eprintln!("❌ csv 저장 실패 ({}): {}", path, e),
         }
+        self.health
     }
 }
 
@@ -72,6 +155,7 @@ where
             Ok(jobs_with_details) => {
                 println!("✅ 상세 정보 수집 완료");
                 self.jobs = jobs_with_details;
+                self.normalize_deadlines();
             }
             Err(e) => {
                 eprintln!("❌ 상세 정보 수집 실패: {}", e);
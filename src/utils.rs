@@ -1,4 +1,6 @@
 use rand::prelude::IndexedRandom;
+use scraper::node::Node;
+use scraper::{ElementRef, Html, Selector};
 use std::time::Duration;
 
 pub fn random_delay() {
@@ -6,3 +8,97 @@ pub fn random_delay() {
     let delay = delays.choose(&mut rand::rng()).unwrap();
     std::thread::sleep(Duration::from_millis(*delay));
 }
+
+/// script/style 내용을 제외하고 화면에 보이는 텍스트만 공백으로 이어 붙임
+pub fn visible_text(document: &Html) -> String {
+    document
+        .root_element()
+        .descendants()
+        .filter_map(|node| match node.value() {
+            Node::Text(text) => Some((node, text)),
+            _ => None,
+        })
+        .filter(|(node, _)| {
+            node.ancestors().all(|ancestor| {
+                ancestor
+                    .value()
+                    .as_element()
+                    .is_none_or(|element| !matches!(element.name(), "script" | "style" | "head"))
+            })
+        })
+        .map(|(_, text)| text.trim())
+        .filter(|text| !text.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// `<dt>항목</dt><dd>값</dd>` 형태의 요약 정보에서 항목 이름으로 값을 찾음
+pub fn definition_value(document: &Html, label: &str) -> Option<String> {
+    let dd = find_definition(document, label)?;
+    let text = dd.text().collect::<Vec<_>>().join(" ");
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    Some(text)
+}
+
+/// `definition_value`와 같은 값을 태그 목록으로 읽음.
+/// `<li>`처럼 나뉜 항목이나 "React, TypeScript"처럼 쉼표로 나열된 항목을 하나씩 돌려줌
+pub fn definition_items(document: &Html, label: &str) -> Vec<String> {
+    let Some(dd) = find_definition(document, label) else {
+        return Vec::new();
+    };
+    dd.text()
+        .flat_map(|text| text.split([',', '·', '|']))
+        .map(|item| item.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|item| !item.is_empty())
+        .collect()
+}
+
+fn find_definition<'a>(document: &'a Html, label: &str) -> Option<ElementRef<'a>> {
+    let dt_selector = Selector::parse("dt").ok()?;
+
+    let dt = document
+        .select(&dt_selector)
+        .find(|dt| dt.text().collect::<String>().trim() == label)?;
+    dt.next_siblings()
+        .filter_map(ElementRef::wrap)
+        .find(|element| element.value().name() == "dd")
+}
+
+/// 검색어처럼 자유 입력값을 쿼리 문자열 값으로 인코딩 ("C++" → "C%2B%2B", "R&D" → "R%26D")
+pub fn encode_query_value(value: &str) -> String {
+    form_urlencoded::byte_serialize(value.as_bytes()).collect()
+}
+
+/// "총 1,234건"처럼 숫자가 들어 있는 요소에서 숫자만 읽음
+pub fn count_in(document: &Html, selector: &str) -> Option<usize> {
+    let selector = Selector::parse(selector).ok()?;
+    let text = document
+        .select(&selector)
+        .next()?
+        .text()
+        .collect::<String>();
+    let digits: String = text.chars().filter(char::is_ascii_digit).collect();
+    digits.parse().ok()
+}
+
+/// 페이지 목록이 일부 구간만 보여줄 때 그 뒤에 페이지가 더 있음을 나타내는 링크 문구
+const NEXT_PAGE_LABELS: [&str; 4] = ["다음", "next", "›", "»"];
+
+/// 페이지 목록의 링크 중 가장 큰 페이지 번호.
+/// "다음" 링크가 있으면 보이는 번호가 마지막 페이지가 아니므로 None
+pub fn last_page_number(document: &Html, selector: &str) -> Option<usize> {
+    let selector = Selector::parse(selector).ok()?;
+    let labels: Vec<String> = document
+        .select(&selector)
+        .map(|link| link.text().collect::<String>().trim().to_string())
+        .collect();
+
+    if labels
+        .iter()
+        .any(|label| NEXT_PAGE_LABELS.contains(&label.to_lowercase().as_str()))
+    {
+        return None;
+    }
+
+    labels.iter().filter_map(|label| label.parse().ok()).max()
+}
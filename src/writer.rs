@@ -1,12 +1,141 @@
-use crate::{Job, Result};
-use std::fs::File;
+use crate::models::{DeadlineKind, Salary};
+use crate::{Error, Job, Result};
+use chrono::NaiveDate;
+use serde::Serialize;
+
+/// `JobRecord` 필드 순서와 같은 고정 컬럼. 뒤에 회사 정보 컬럼과 url이 붙음
+const JOB_COLUMNS: [&str; 28] = [
+    "title",
+    "company",
+    "company_id",
+    "experience_years",
+    "min_years",
+    "max_years",
+    "accepts_newcomer",
+    "experience_any",
+    "deadline",
+    "deadline_date",
+    "deadline_kind",
+    "location",
+    "sido",
+    "sigungu",
+    "dong",
+    "salary_min",
+    "salary_max",
+    "salary_negotiable",
+    "employment_type",
+    "company_stage",
+    "investment_round",
+    "company_size",
+    "tech_stack",
+    "main_tasks",
+    "requirements",
+    "preferred",
+    "benefits",
+    "hiring_process",
+];
+
+#[derive(Serialize)]
+struct JobRecord<'a> {
+    title: &'a str,
+    company: &'a str,
+    company_id: Option<&'a str>,
+    experience_years: &'a str,
+    min_years: Option<u8>,
+    max_years: Option<u8>,
+    accepts_newcomer: bool,
+    experience_any: bool,
+    deadline: &'a str,
+    deadline_date: Option<NaiveDate>,
+    deadline_kind: Option<DeadlineKind>,
+    location: &'a str,
+    sido: Option<&'a str>,
+    sigungu: Option<&'a str>,
+    dong: Option<&'a str>,
+    salary_min: Option<u32>,
+    salary_max: Option<u32>,
+    salary_negotiable: bool,
+    employment_type: &'a str,
+    company_stage: Option<&'a str>,
+    investment_round: Option<&'a str>,
+    company_size: Option<&'a str>,
+    tech_stack: String,
+    main_tasks: &'a str,
+    requirements: &'a str,
+    preferred: &'a str,
+    benefits: &'a str,
+    hiring_process: &'a str,
+}
+
+impl<'a> From<&'a Job> for JobRecord<'a> {
+    fn from(job: &'a Job) -> Self {
+        let (salary_min, salary_max) = match job.salary {
+            Some(Salary::Range { min, max }) => (min, max),
+            _ => (None, None),
+        };
+
+        Self {
+            title: &job.title,
+            company: &job.company,
+            company_id: job.company_id.as_deref(),
+            experience_years: &job.experience_years,
+            min_years: job.experience.min_years,
+            max_years: job.experience.max_years,
+            accepts_newcomer: job.experience.accepts_newcomer,
+            experience_any: job.experience.any,
+            deadline: &job.deadline,
+            deadline_date: job.deadline_date,
+            deadline_kind: job.deadline_kind,
+            location: &job.location,
+            sido: job.region.sido.as_deref(),
+            sigungu: job.region.sigungu.as_deref(),
+            dong: job.region.dong.as_deref(),
+            salary_min,
+            salary_max,
+            salary_negotiable: job.salary == Some(Salary::Negotiable),
+            employment_type: &job.employment_type,
+            company_stage: job.company_stage.as_deref(),
+            investment_round: job.investment_round.as_deref(),
+            company_size: job.company_size.as_deref(),
+            tech_stack: job.tech_stack.join(", "),
+            main_tasks: &job.description.main_tasks,
+            requirements: &job.description.requirements,
+            preferred: &job.description.preferred,
+            benefits: &job.description.benefits,
+            hiring_process: &job.description.hiring_process,
+        }
+    }
+}
 
 pub fn save_to_csv(jobs: &[Job], file_path: &str) -> Result<()> {
-    let file = File::create(file_path)?;
-    let mut writer = csv::Writer::from_writer(file);
+    write_csv(jobs, file_path).map_err(|source| Error::Output {
+        path: file_path.to_string(),
+        source,
+    })
+}
+
+/// 공고들에 있는 회사 정보 키를 처음 나온 순서대로 모음
+fn attribute_keys(jobs: &[Job]) -> Vec<&str> {
+    let mut keys = Vec::new();
+    for attribute in jobs.iter().flat_map(|job| &job.company_attributes) {
+        if !keys.contains(&attribute.key.as_str()) {
+            keys.push(attribute.key.as_str());
+        }
+    }
+    keys
+}
+
+fn write_csv(jobs: &[Job], file_path: &str) -> csv::Result<()> {
+    let keys = attribute_keys(jobs);
+    let mut writer = csv::WriterBuilder::new()
+        .has_headers(false)
+        .from_path(file_path)?;
+
+    writer.write_record(JOB_COLUMNS.iter().chain(&keys).chain(&["url"]))?;
 
     for job in jobs {
-        writer.serialize(job)?;
+        let attributes: Vec<Option<&str>> = keys.iter().map(|key| job.attribute(key)).collect();
+        writer.serialize((JobRecord::from(job), attributes, job.url.as_str()))?;
     }
 
     writer.flush()?;
@@ -0,0 +1,153 @@
+use jd_crawler::crawler::DetailCrawler;
+use jd_crawler::pipeline::Crawler;
+use jd_crawler::{
+    AtsBoardClient, AtsBoardConfig, AtsFlavor, DetailCrawlConfig, FetcherKind, Result,
+};
+use std::path::{Path, PathBuf};
+
+fn fixture_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/ats")
+}
+
+fn config() -> AtsBoardConfig {
+    AtsBoardConfig {
+        boards: vec![
+            "https://dable.career.greetinghr.com",
+            "https://daangn.ninehire.site",
+            "https://jobs.lever.co/sendbird",
+            "https://boards.greenhouse.io/moloco",
+            "https://careers.example.com",
+        ],
+        thread_count: 2,
+        exclude_keywords: vec!["Android"],
+        locations: vec![],
+        fetcher: FetcherKind::Fixture(fixture_dir()),
+    }
+}
+
+#[test]
+fn detects_ats_flavor_from_board_url() {
+    assert_eq!(
+        AtsFlavor::detect("https://toss.career.greetinghr.com/"),
+        Some(AtsFlavor::Greeting)
+    );
+    assert_eq!(
+        AtsFlavor::detect("https://daangn.ninehire.site"),
+        Some(AtsFlavor::Ninehire)
+    );
+    assert_eq!(
+        AtsFlavor::detect("https://jobs.lever.co/sendbird"),
+        Some(AtsFlavor::Lever)
+    );
+    assert_eq!(
+        AtsFlavor::detect("https://boards.greenhouse.io/moloco"),
+        Some(AtsFlavor::Greenhouse)
+    );
+    assert_eq!(AtsFlavor::detect("https://careers.example.com"), None);
+}
+
+#[test]
+fn crawls_every_supported_board() -> Result<()> {
+    let jobs = AtsBoardClient::new(config()).start_crawl()?.jobs;
+
+    let postings: Vec<_> = jobs
+        .iter()
+        .map(|job| (job.company.as_str(), job.title.as_str()))
+        .collect();
+    assert_eq!(
+        postings,
+        [
+            ("데이블", "프론트엔드 엔지니어"),
+            ("데이블", "데이터 분석 인턴"),
+            ("당근", "Software Engineer, Frontend"),
+            ("Sendbird", "Senior Frontend Engineer"),
+            ("Sendbird", "Solutions Engineer"),
+            ("moloco", "Software Engineer, Web"),
+            ("moloco", "Machine Learning Engineer"),
+        ]
+    );
+
+    let greeting = &jobs[0];
+    assert_eq!(greeting.url, "https://dable.career.greetinghr.com/o/101");
+    assert_eq!(greeting.location, "서울 중구");
+    assert_eq!(greeting.employment_type, "정규직");
+    assert_eq!(greeting.experience.min_years, Some(3));
+    assert_eq!(jobs[1].employment_type, "인턴");
+    assert!(jobs[1].experience.accepts_newcomer);
+
+    let ninehire = &jobs[2];
+    assert_eq!(
+        ninehire.url,
+        "https://daangn.ninehire.site/job_posting/aBcD1234"
+    );
+    assert_eq!(ninehire.experience_years, "경력 5년 이상");
+
+    let lever = &jobs[3];
+    assert_eq!(lever.url, "https://jobs.lever.co/sendbird/1a2b3c");
+    assert_eq!(lever.employment_type, "Full-time");
+    assert_eq!(lever.region.sigungu.as_deref(), Some("서초구"));
+
+    let greenhouse = &jobs[5];
+    assert_eq!(
+        greenhouse.url,
+        "https://boards.greenhouse.io/moloco/jobs/5012345"
+    );
+    assert_eq!(greenhouse.location, "서울 강남구");
+    Ok(())
+}
+
+#[test]
+fn filters_board_postings_by_location() -> Result<()> {
+    let client = AtsBoardClient::new(AtsBoardConfig {
+        locations: vec!["서울 강남·서초"],
+        ..config()
+    });
+    let jobs = client.start_crawl()?.jobs;
+
+    let titles: Vec<_> = jobs.iter().map(|job| job.title.as_str()).collect();
+    assert_eq!(
+        titles,
+        [
+            "Software Engineer, Frontend",
+            "Senior Frontend Engineer",
+            "Software Engineer, Web"
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn board_without_postings_is_empty_not_failed() -> Result<()> {
+    let output = AtsBoardClient::new(AtsBoardConfig {
+        boards: vec!["https://jobs.lever.co/quietcompany"],
+        ..config()
+    })
+    .start_crawl()?;
+
+    assert!(output.jobs.is_empty());
+    assert_eq!(output.pages.len(), 1);
+    assert_eq!(output.pages[0].jobs, 0);
+    assert_eq!(output.pages[0].error, None);
+    Ok(())
+}
+
+#[test]
+fn fetches_posting_details() -> Result<()> {
+    let client = AtsBoardClient::new(config());
+    let jobs = client.start_crawl()?.jobs;
+
+    let details = client.crawl_job_details(
+        jobs.into_iter().take(1).collect(),
+        &DetailCrawlConfig {
+            thread_count: 1,
+            includes: vec![],
+            ..Default::default()
+        },
+    )?;
+
+    let job = &details[0];
+    assert_eq!(job.description.main_tasks, "광고 플랫폼 대시보드 개발");
+    assert_eq!(job.description.preferred, "Vite 기반 빌드 환경 구성 경험");
+    assert_eq!(job.tech_stack, ["React", "TypeScript", "Vite"]);
+    Ok(())
+}
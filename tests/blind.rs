@@ -0,0 +1,283 @@
+use chrono::{Duration as ChronoDuration, Utc};
+use jd_crawler::{
+    BlindEnricher, CachedEnrichment, CompanyAttribute, EnricherConfig, EnrichmentCache,
+    FetcherBackend, FetcherKind, FixtureBackend, Job, JobEnricher, PageFetcher, Result,
+};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Duration;
+
+fn fixture_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/golden")
+}
+
+fn cache_path(name: &str) -> PathBuf {
+    let path = Path::new(env!("CARGO_TARGET_TMPDIR")).join(name);
+    let _ = std::fs::remove_file(&path);
+    path
+}
+
+/// 이동한 URL을 기록하는 fixture 백엔드
+struct RecordingBackend {
+    inner: FixtureBackend,
+    visited: &'static Mutex<Vec<String>>,
+}
+
+struct RecordingPage {
+    inner: Box<dyn PageFetcher>,
+    visited: &'static Mutex<Vec<String>>,
+}
+
+impl RecordingBackend {
+    fn new() -> Self {
+        Self {
+            inner: FixtureBackend::new("블라인드", fixture_dir()),
+            visited: Box::leak(Box::new(Mutex::new(Vec::new()))),
+        }
+    }
+
+    fn visited(&self) -> Vec<String> {
+        let mut visited = self.visited.lock().unwrap().clone();
+        visited.sort();
+        visited
+    }
+}
+
+impl FetcherBackend for RecordingBackend {
+    fn new_page(&self) -> Result<Box<dyn PageFetcher>> {
+        Ok(Box::new(RecordingPage {
+            inner: self.inner.new_page()?,
+            visited: self.visited,
+        }))
+    }
+}
+
+impl PageFetcher for RecordingPage {
+    fn navigate_to(&self, url: &str) -> Result<()> {
+        self.visited.lock().unwrap().push(url.to_string());
+        self.inner.navigate_to(url)
+    }
+
+    fn get_content(&self) -> Result<String> {
+        self.inner.get_content()
+    }
+
+    fn wait_for_element(&self, selector: &str) -> Result<()> {
+        self.inner.wait_for_element(selector)
+    }
+
+    fn evaluate(&self, script: &str) -> Result<()> {
+        self.inner.evaluate(script)
+    }
+}
+
+fn job(company: &str) -> Job {
+    Job {
+        title: "프론트엔드 개발자".to_string(),
+        company: company.to_string(),
+        ..Default::default()
+    }
+}
+
+fn config(cache_path: Option<PathBuf>, cache_ttl: Duration) -> EnricherConfig {
+    EnricherConfig {
+        thread_count: 2,
+        cache_path,
+        cache_ttl,
+        fetcher: FetcherKind::Fixture(fixture_dir()),
+    }
+}
+
+#[test]
+fn looks_up_each_company_once() -> Result<()> {
+    let backend = RecordingBackend::new();
+    let enricher = BlindEnricher::new(config(None, Duration::ZERO));
+    let jobs = vec![
+        job("네이버"),
+        job("카카오"),
+        job("네이버(주)"),
+        job("㈜네이버"),
+        job("주식회사 카카오"),
+        job("토스 (비바리퍼블리카)"),
+    ];
+
+    let enriched = enricher.enrich_all_jobs(&backend, &jobs)?;
+
+    assert_eq!(
+        backend.visited(),
+        vec![
+            "https://www.teamblind.com/kr/company/네이버/reviews",
+            "https://www.teamblind.com/kr/company/카카오/reviews",
+            "https://www.teamblind.com/kr/company/토스/reviews",
+        ]
+    );
+    assert_eq!(enriched.len(), 6);
+    assert_eq!(enriched[3].attribute("rating"), Some("3.7"));
+    assert_eq!(enriched[2].company, "네이버(주)");
+    assert_eq!(enriched[2].attribute("rating"), Some("3.7"));
+    assert_eq!(enriched[2].attribute("review_count"), Some("10234"));
+    assert_eq!(enriched[4].attribute("rating"), Some("3.6"));
+    assert_eq!(enriched[5].attribute("rating"), Some("4.1"));
+    Ok(())
+}
+
+fn write_cache(path: &Path, company: &str, rating: &str, age: ChronoDuration) {
+    let mut cache = EnrichmentCache::default();
+    cache.insert(
+        "블라인드",
+        company,
+        CachedEnrichment {
+            attributes: vec![CompanyAttribute {
+                key: "rating".to_string(),
+                value: rating.to_string(),
+                source: "블라인드".to_string(),
+                fetched_at: Utc::now() - age,
+            }],
+            fetched_at: Utc::now() - age,
+        },
+    );
+    cache.save(path).unwrap();
+}
+
+#[test]
+fn uses_fresh_cache_without_fetching() -> Result<()> {
+    let path = cache_path("blind_fresh.cache.json");
+    write_cache(&path, "네이버", "4.5", ChronoDuration::days(1));
+
+    let backend = RecordingBackend::new();
+    let enricher = BlindEnricher::new(config(
+        Some(path.clone()),
+        Duration::from_secs(7 * 24 * 60 * 60),
+    ));
+
+    let enriched = enricher.enrich_all_jobs(&backend, &[job("네이버"), job("카카오")])?;
+
+    assert_eq!(
+        backend.visited(),
+        vec!["https://www.teamblind.com/kr/company/카카오/reviews"]
+    );
+    assert_eq!(enriched[0].attribute("rating"), Some("4.5"));
+    assert_eq!(enriched[1].attribute("rating"), Some("3.6"));
+
+    let saved = EnrichmentCache::load(&path);
+    assert_eq!(
+        saved
+            .get("블라인드", "카카오")
+            .map(|entry| entry.attributes.len()),
+        Some(2)
+    );
+    Ok(())
+}
+
+#[test]
+fn refetches_stale_cache_entries() -> Result<()> {
+    let path = cache_path("blind_stale.cache.json");
+    write_cache(&path, "토스", "1.0", ChronoDuration::days(30));
+
+    let backend = RecordingBackend::new();
+    let enricher = BlindEnricher::new(config(
+        Some(path.clone()),
+        Duration::from_secs(7 * 24 * 60 * 60),
+    ));
+
+    let enriched = enricher.enrich_all_jobs(&backend, &[job("토스")])?;
+
+    assert_eq!(
+        backend.visited(),
+        vec!["https://www.teamblind.com/kr/company/토스/reviews"]
+    );
+    assert_eq!(enriched[0].attribute("rating"), Some("4.1"));
+
+    let saved = EnrichmentCache::load(&path);
+    let entry = saved.get("블라인드", "토스").unwrap();
+    assert_eq!(entry.attributes[0].value, "4.1");
+    assert!(entry.fetched_at > Utc::now() - ChronoDuration::minutes(1));
+    Ok(())
+}
+
+#[test]
+fn does_not_cache_pages_without_fields() -> Result<()> {
+    let path = cache_path("blind_empty.cache.json");
+    let enricher = BlindEnricher::new(EnricherConfig {
+        thread_count: 1,
+        cache_path: Some(path.clone()),
+        cache_ttl: Duration::from_secs(7 * 24 * 60 * 60),
+        fetcher: FetcherKind::Fixture(
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/blind"),
+        ),
+    });
+
+    let enriched = enricher.start_enrich(&[job("없는회사"), job("토스")])?;
+
+    assert!(enriched[0].company_attributes.is_empty());
+    let saved = EnrichmentCache::load(&path);
+    assert!(saved.get("블라인드", "없는회사").is_none());
+    assert!(saved.get("블라인드", "토스").is_some());
+    Ok(())
+}
+
+#[test]
+fn reads_sub_scores_only_from_rating_summary() -> Result<()> {
+    let enricher = BlindEnricher::new(config(None, Duration::ZERO));
+    let html = r#"
+        <section class="rating_wrp">
+          <dl class="rating_detail">
+            <div><dt>워라밸</dt><dd>3.2</dd></div>
+            <div><dt>경영진</dt><dd>평가 없음</dd></div>
+          </dl>
+        </section>
+        <div class="review_item">
+          <div class="parag"><p>장점 워라밸 5 만점에 5, 추천율 100% 회사</p></div>
+          <div class="parag"><p>단점 사내문화 2 정도, 커리어 향상 1</p></div>
+        </div>
+    "#;
+
+    let fields = enricher.parse_html(html)?;
+    let keys: Vec<_> = fields.iter().map(|(key, _)| *key).collect();
+
+    assert_eq!(keys, ["work_life_balance_rating", "pros", "cons"]);
+    assert_eq!(fields[0].1, "3.2");
+    Ok(())
+}
+
+#[test]
+fn parses_detailed_company_metrics() -> Result<()> {
+    let fixture_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/blind");
+    let enricher = BlindEnricher::new(EnricherConfig {
+        thread_count: 1,
+        cache_path: None,
+        cache_ttl: Duration::ZERO,
+        fetcher: FetcherKind::Fixture(fixture_dir),
+    });
+
+    let enriched = enricher.start_enrich(&[job("토스(비바리퍼블리카)")])?;
+    let job = &enriched[0];
+
+    assert_eq!(job.attribute("rating"), Some("4.1"));
+    assert_eq!(job.attribute("review_count"), Some("1523"));
+    assert_eq!(job.attribute("career_rating"), Some("4.3"));
+    assert_eq!(job.attribute("work_life_balance_rating"), Some("3.2"));
+    assert_eq!(job.attribute("compensation_rating"), Some("4.5"));
+    assert_eq!(job.attribute("culture_rating"), Some("4.0"));
+    assert_eq!(job.attribute("management_rating"), Some("3.9"));
+    assert_eq!(job.attribute("ceo_approval"), Some("81"));
+    assert_eq!(job.attribute("recommend_rate"), Some("74"));
+    assert_eq!(
+        job.attribute("pros"),
+        Some(
+            "동료 수준이 높고 빠르게 성장할 수 있음\n\
+             연봉과 스톡옵션 등 보상이 업계 최고 수준\n\
+             자율과 책임을 중시하는 문화"
+        )
+    );
+    assert_eq!(
+        job.attribute("cons").map(|cons| cons.lines().count()),
+        Some(3)
+    );
+    assert!(
+        job.company_attributes
+            .iter()
+            .all(|attribute| attribute.source == "블라인드")
+    );
+    Ok(())
+}
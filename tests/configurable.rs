@@ -0,0 +1,216 @@
+use jd_crawler::crawler::DetailCrawler;
+use jd_crawler::pipeline::Crawler;
+use jd_crawler::{
+    ConfigurableClient, ConfigurableCrawlConfig, DetailCrawlConfig, Error, FetcherKind,
+    JobListPaginatedCrawler, Result, Salary, SaraminClient, SaraminCrawlConfig, SiteDefinition,
+};
+use std::path::{Path, PathBuf};
+
+fn root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+fn fixture_dir(site: &str) -> PathBuf {
+    root().join("tests/fixtures").join(site)
+}
+
+fn config(definition: SiteDefinition, site: &str) -> ConfigurableCrawlConfig {
+    ConfigurableCrawlConfig {
+        definition,
+        variables: vec![],
+        total_pages: Some(1),
+        thread_count: 1,
+        exclude_keywords: vec![],
+        locations: vec![],
+        fetcher: FetcherKind::Fixture(fixture_dir(site)),
+    }
+}
+
+#[test]
+fn saramin_definition_matches_built_in_client() -> Result<()> {
+    let html =
+        std::fs::read_to_string(fixture_dir("golden").join(
+            "www.saramin.co.kr_zf_user_search_recruit_searchword_프론트엔드_recruitPage_1.html",
+        ))
+        .unwrap();
+
+    let definition = SiteDefinition::load(root().join("sites/saramin.toml"))?;
+    let configurable = ConfigurableClient::new(config(definition, "golden")).parse_html(&html)?;
+    let built_in = SaraminClient::new(SaraminCrawlConfig::default()).parse_html(&html)?;
+
+    assert_eq!(configurable.len(), 4);
+    for (left, right) in configurable.iter().zip(&built_in) {
+        assert_eq!(left.title, right.title);
+        assert_eq!(left.company, right.company);
+        assert_eq!(left.experience_years, right.experience_years);
+        assert_eq!(left.experience, right.experience);
+        assert_eq!(left.deadline, right.deadline);
+        assert_eq!(left.location, right.location);
+        assert_eq!(left.region, right.region);
+        assert_eq!(left.salary, right.salary);
+        assert_eq!(left.url, right.url);
+    }
+    Ok(())
+}
+
+#[test]
+fn crawls_list_and_detail_pages_from_json_definition() -> Result<()> {
+    let definition = SiteDefinition::load(root().join("sites/programmers.json"))?;
+    let client = ConfigurableClient::new(ConfigurableCrawlConfig {
+        variables: vec![
+            ("category", "4"),
+            ("min_salary", "5000"),
+            ("min_years", "0"),
+            ("max_years", "5"),
+            ("tag", "React"),
+        ],
+        exclude_keywords: vec!["Android"],
+        ..config(definition, "programmers")
+    });
+
+    let jobs = client.start_crawl()?.jobs;
+    assert_eq!(jobs.len(), 3);
+    assert_eq!(jobs[0].company, "그렙(주)");
+    assert_eq!(jobs[0].tech_stack, ["React", "TypeScript", "Redux"]);
+    assert_eq!(
+        client.parse_last_page(
+            &std::fs::read_to_string(fixture_dir("programmers").join(
+                "career.programmers.co.kr_job_order_recent_job_category_ids_4_min_salary_5000_min_career_0_max_career_5_tags_React_page_1.html",
+            ))
+            .unwrap()
+        ),
+        Some(7)
+    );
+
+    let details = client.crawl_job_details(
+        jobs.into_iter().take(1).collect(),
+        &DetailCrawlConfig {
+            thread_count: 1,
+            includes: vec![],
+            ..Default::default()
+        },
+    )?;
+
+    let job = &details[0];
+    assert_eq!(job.deadline, "2025-12-31");
+    assert_eq!(job.employment_type, "정규직");
+    assert_eq!(
+        job.salary,
+        Some(Salary::Range {
+            min: Some(5000),
+            max: Some(7500)
+        })
+    );
+    assert_eq!(
+        job.tech_stack,
+        ["React", "TypeScript", "Redux", "Emotion", "Storybook"]
+    );
+    assert_eq!(job.description.benefits, "자기계발비 연 200만원 지원");
+    Ok(())
+}
+
+#[test]
+fn rejects_broken_definitions() {
+    let definition = |list: &str| {
+        format!(
+            "name = \"테스트\"\nbase_url = \"https://example.com\"\nlist_url = \"https://example.com/jobs\"\n\n[list]\n{}",
+            list
+        )
+    };
+
+    let valid = SiteDefinition::from_toml(&definition(
+        "card = \"li.job\"\n[list.fields]\ntitle = \"h3\"\nurl = { selector = \"a\", attr = \"href\" }",
+    ));
+    assert!(valid.is_ok());
+
+    // selector 문법 오류, 알 수 없는 항목, 필수 필드 누락은 읽을 때 바로 알려 줌
+    for list in [
+        "card = \"li.job[\"\n[list.fields]\ntitle = \"h3\"\nurl = \"a\"",
+        "card = \"li.job\"\n[list.fields]\ntitle = \"h3\"\nurl = \"a\"\ncompnay = \"p\"",
+        "card = \"li.job\"\n[list.fields]\ntitle = \"h3\"",
+    ] {
+        let result = SiteDefinition::from_toml(&definition(list));
+        assert!(
+            matches!(result, Err(Error::Definition { .. })),
+            "{:?}",
+            result
+        );
+    }
+
+    let json = SiteDefinition::from_json(r#"{"name": "테스트"}"#);
+    assert!(matches!(json, Err(Error::Definition { .. })));
+}
+
+#[test]
+fn fills_list_url_placeholders() -> Result<()> {
+    let definition = SiteDefinition::load(root().join("sites/saramin.toml"))?;
+
+    let client = ConfigurableClient::new(ConfigurableCrawlConfig {
+        variables: vec![("keyword", "C++ & C#")],
+        ..config(definition.clone(), "golden")
+    });
+    assert_eq!(
+        client.build_url()?,
+        "https://www.saramin.co.kr/zf_user/search/recruit?searchword=C%2B%2B+%26+C%23"
+    );
+
+    // 값을 지정하지 않은 자리표시자가 그대로 요청되지 않도록 정의 오류로 알려 줌
+    let client = ConfigurableClient::new(config(definition, "golden"));
+    let result = client.build_url();
+    assert!(
+        matches!(&result, Err(Error::Definition { message, .. }) if message.contains("{keyword}")),
+        "{:?}",
+        result
+    );
+    assert!(client.start_crawl().is_err());
+    Ok(())
+}
+
+#[test]
+fn drops_unfilled_optional_query_params() -> Result<()> {
+    let definition = SiteDefinition::load(root().join("sites/programmers.json"))?;
+
+    let client = ConfigurableClient::new(ConfigurableCrawlConfig {
+        variables: vec![("category", "4"), ("min_salary", ""), ("tag", "C#")],
+        ..config(definition, "programmers")
+    });
+    assert_eq!(
+        client.build_url()?,
+        "https://career.programmers.co.kr/job?order=recent&job_category_ids=4&tags=C%23"
+    );
+    Ok(())
+}
+
+#[test]
+fn applies_includes_to_list_cards_without_detail_rules() -> Result<()> {
+    let mut definition = SiteDefinition::load(root().join("sites/programmers.json"))?;
+    definition.detail = None;
+    let client = ConfigurableClient::new(ConfigurableCrawlConfig {
+        variables: vec![
+            ("category", "4"),
+            ("min_salary", "5000"),
+            ("min_years", "0"),
+            ("max_years", "5"),
+            ("tag", "React"),
+        ],
+        ..config(definition, "programmers")
+    });
+
+    let jobs = client.start_crawl()?.jobs;
+    let details = client.crawl_job_details(
+        jobs.clone(),
+        &DetailCrawlConfig {
+            thread_count: 1,
+            includes: vec!["Redux"],
+            ..Default::default()
+        },
+    )?;
+
+    assert!(!details.is_empty() && details.len() < jobs.len());
+    assert!(
+        details
+            .iter()
+            .all(|job| job.tech_stack.iter().any(|tech| tech == "Redux"))
+    );
+    Ok(())
+}
@@ -0,0 +1,114 @@
+use chrono::NaiveDate;
+use jd_crawler::{
+    BlindEnricher, CrawlPipeline, DetailCrawlConfig, EnricherConfig, FetcherKind, Result,
+    SaraminClient, SaraminCrawlConfig, SaraminJobCategory, WantedClient, WantedCrawlConfig,
+    WantedCrawlMode, WantedJobCategory, WantedJobSort, WantedJobSubcategory,
+};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+fn fixture_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/golden")
+}
+
+fn crawl_date() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2025, 11, 20).unwrap()
+}
+
+fn output_path(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_TARGET_TMPDIR")).join(name)
+}
+
+/// `UPDATE_GOLDEN=1 cargo test`로 기대 결과를 갱신
+fn assert_golden(actual_path: &Path, expected_name: &str) {
+    let expected_path = fixture_dir().join(expected_name);
+    let actual = std::fs::read_to_string(actual_path).expect("csv 결과 파일 없음");
+
+    if std::env::var_os("UPDATE_GOLDEN").is_some() {
+        std::fs::write(&expected_path, &actual).unwrap();
+        return;
+    }
+
+    let expected = std::fs::read_to_string(&expected_path).expect("기대 결과 파일 없음");
+    assert_eq!(actual, expected, "{} 결과가 다름", expected_name);
+}
+
+#[test]
+fn wanted_pipeline_matches_golden_csv() -> Result<()> {
+    let fetcher = FetcherKind::Fixture(fixture_dir());
+    let output = output_path("wanted.csv");
+
+    CrawlPipeline::new()
+        .with_crawl_date(crawl_date())
+        .crawl(WantedClient::new(WantedCrawlConfig {
+            category: WantedJobCategory::Development,
+            subcategory: WantedJobSubcategory::Frontend,
+            sort: WantedJobSort::Recommend,
+            location_tags: vec![],
+            skill_tags: vec![],
+            total_pages: 3,
+            min_years: 0,
+            max_years: 5,
+            thread_count: 1,
+            exclude_keywords: vec!["IOS"],
+            locations: vec![],
+            fetcher: fetcher.clone(),
+            mode: WantedCrawlMode::Html,
+        }))?
+        .fetch_details(DetailCrawlConfig {
+            thread_count: 2,
+            includes: vec!["React"],
+            ..Default::default()
+        })
+        .enrich(BlindEnricher::new(EnricherConfig {
+            thread_count: 1,
+            cache_path: None,
+            cache_ttl: Duration::ZERO,
+            fetcher,
+        }))
+        .save(output.to_str().unwrap());
+
+    assert_golden(&output, "wanted.expected.csv");
+    Ok(())
+}
+
+#[test]
+fn saramin_pipeline_matches_golden_csv() -> Result<()> {
+    let fetcher = FetcherKind::Fixture(fixture_dir());
+    let output = output_path("saramin.csv");
+
+    CrawlPipeline::new()
+        .with_crawl_date(crawl_date())
+        .crawl(SaraminClient::new(SaraminCrawlConfig {
+            category: SaraminJobCategory::Frontend,
+            careers: vec![],
+            min_years: None,
+            max_years: None,
+            education: None,
+            employment_types: vec![],
+            regions: vec![],
+            min_salary: None,
+            company_types: vec![],
+            sort: None,
+            total_pages: Some(1),
+            thread_count: 1,
+            exclude_keywords: vec!["안드로이드"],
+            locations: vec!["서울 강남·서초", "판교"],
+            fetcher: fetcher.clone(),
+        }))?
+        .fetch_details(DetailCrawlConfig {
+            thread_count: 2,
+            includes: vec![],
+            ..Default::default()
+        })
+        .enrich(BlindEnricher::new(EnricherConfig {
+            thread_count: 1,
+            cache_path: None,
+            cache_ttl: Duration::ZERO,
+            fetcher,
+        }))
+        .save(output.to_str().unwrap());
+
+    assert_golden(&output, "saramin.expected.csv");
+    Ok(())
+}
@@ -0,0 +1,149 @@
+use jd_crawler::{
+    ConfigurableClient, ConfigurableCrawlConfig, CrawlPipeline, FetcherKind, HealthConfig,
+    JobField, Result, SaraminClient, SaraminCrawlConfig, SiteDefinition,
+};
+use std::path::{Path, PathBuf};
+
+fn fixture_dir(site: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(site)
+}
+
+/// 골든 테스트의 사람인 검색 결과 페이지를 selector만 바꿔서 읽는 정의
+fn saramin_definition(name: &str, card: &str, company: &str) -> SiteDefinition {
+    SiteDefinition::from_toml(&format!(
+        r##"
+name = "{name}"
+base_url = "https://www.saramin.co.kr"
+list_url = "https://www.saramin.co.kr/zf_user/search/recruit?searchword=프론트엔드"
+
+[pagination]
+kind = "page"
+param = "recruitPage"
+
+[list]
+wait_for = "#recruit_info_list"
+card = "{card}"
+
+[list.fields]
+title = {{ selector = "h2.job_tit a", attr = "title" }}
+url = {{ selector = "h2.job_tit a", attr = "href" }}
+company = "{company}"
+deadline = "span.date"
+location = {{ selector = "div.job_condition span", index = 0 }}
+experience_years = {{ selector = "div.job_condition span", index = 1 }}
+"##
+    ))
+    .unwrap()
+}
+
+fn configurable(definition: SiteDefinition, total_pages: usize) -> ConfigurableClient {
+    ConfigurableClient::new(configurable_config(definition, total_pages))
+}
+
+fn configurable_config(definition: SiteDefinition, total_pages: usize) -> ConfigurableCrawlConfig {
+    ConfigurableCrawlConfig {
+        definition,
+        variables: vec![],
+        total_pages: Some(total_pages),
+        thread_count: 1,
+        exclude_keywords: vec![],
+        locations: vec![],
+        fetcher: FetcherKind::Fixture(fixture_dir("golden")),
+    }
+}
+
+#[test]
+fn reports_healthy_crawl() -> Result<()> {
+    let report = CrawlPipeline::new()
+        .crawl(SaraminClient::new(SaraminCrawlConfig {
+            total_pages: Some(1),
+            fetcher: FetcherKind::Fixture(fixture_dir("golden")),
+            ..SaraminCrawlConfig::default()
+        }))?
+        .health_report(&HealthConfig::default());
+
+    assert!(report.healthy, "{:?}", report.issues);
+    assert_eq!(report.source, "사람인");
+    assert_eq!(report.job_count, 4);
+    assert_eq!(report.pages.len(), 1);
+    assert_eq!(report.pages[0].jobs, 4);
+
+    let salary = report
+        .fields
+        .iter()
+        .find(|field| field.field == "salary")
+        .unwrap();
+    assert_eq!(salary.hits, 2);
+    assert_eq!(salary.hit_rate, 0.5);
+    assert_eq!(salary.min_hit_rate, None);
+    Ok(())
+}
+
+#[test]
+fn flags_fields_below_threshold() -> Result<()> {
+    // 회사명 class가 바뀌어 모든 카드의 회사명이 빈 문자열이 된 상황
+    let client = configurable(
+        saramin_definition("회사명 변경", "div.item_recruit", "strong.company_nm a"),
+        1,
+    );
+    let report = CrawlPipeline::new()
+        .crawl(client)?
+        .health_report(&HealthConfig {
+            min_hit_rates: vec![(JobField::Company, 0.9), (JobField::Salary, 0.0)],
+            report_path: None,
+        });
+
+    assert!(!report.healthy);
+    assert_eq!(report.job_count, 4);
+    assert_eq!(report.issues, ["company 추출률 0.0% (기준 90.0%)"]);
+
+    let json: serde_json::Value = serde_json::from_str(&report.to_json()).unwrap();
+    assert_eq!(json["healthy"], false);
+    assert_eq!(json["fields"][1]["field"], "company");
+    assert_eq!(json["fields"][1]["ok"], false);
+    Ok(())
+}
+
+#[test]
+fn flags_pages_without_cards_and_failed_pages() -> Result<()> {
+    // 카드 class가 바뀌어 목록이 비고, 저장된 적 없는 2페이지는 요청이 실패한 상황
+    let client = configurable(
+        saramin_definition("카드 변경", "div.recruit_card", "strong.corp_name a"),
+        2,
+    );
+    let report = CrawlPipeline::new()
+        .crawl(client)?
+        .health_report(&HealthConfig::default());
+
+    assert!(!report.healthy);
+    assert_eq!(report.job_count, 0);
+    assert_eq!(report.pages.len(), 2);
+    assert_eq!(report.pages[0].jobs, 0);
+    assert!(report.pages[1].error.is_some());
+    assert_eq!(report.issues[0], "수집된 공고 없음");
+    assert!(report.issues[1].starts_with("페이지 1 공고 0개"));
+    assert!(report.issues[2].starts_with("페이지 2 실패"));
+    Ok(())
+}
+
+#[test]
+fn counts_cards_before_filtering() -> Result<()> {
+    // 지역 조건에 맞는 공고가 없어도 목록 페이지는 정상으로 봄
+    let client = ConfigurableClient::new(ConfigurableCrawlConfig {
+        locations: vec!["제주"],
+        ..configurable_config(
+            saramin_definition("지역 필터", "div.item_recruit", "strong.corp_name a"),
+            1,
+        )
+    });
+    let report = CrawlPipeline::new()
+        .crawl(client)?
+        .health_report(&HealthConfig::default());
+
+    assert_eq!(report.job_count, 0);
+    assert_eq!(report.pages[0].jobs, 4);
+    assert_eq!(report.issues, ["수집된 공고 없음"]);
+    Ok(())
+}
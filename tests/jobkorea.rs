@@ -0,0 +1,202 @@
+use jd_crawler::crawler::{DetailCrawler, JobCrawler};
+use jd_crawler::pipeline::Crawler;
+use jd_crawler::{
+    DetailCrawlConfig, FetcherKind, JobKoreaClient, JobKoreaCrawlConfig, JobKoreaDuty,
+    JobListPaginatedCrawler, Result, Salary,
+};
+use std::path::{Path, PathBuf};
+
+fn fixture_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/jobkorea")
+}
+
+fn config() -> JobKoreaCrawlConfig {
+    JobKoreaCrawlConfig {
+        keyword: "프론트엔드",
+        duty: Some(JobKoreaDuty::Frontend),
+        min_years: Some(0),
+        max_years: Some(5),
+        total_pages: Some(1),
+        thread_count: 1,
+        exclude_keywords: vec!["안드로이드"],
+        locations: vec![],
+        fetcher: FetcherKind::Fixture(fixture_dir()),
+    }
+}
+
+fn list_html() -> String {
+    let path = fixture_dir().join(
+        "www.jobkorea.co.kr_Search__stext_프론트엔드_tabType_recruit_duty_1000230_careerType_1_2_careerMax_5_Page_No_1.html",
+    );
+    std::fs::read_to_string(path).unwrap()
+}
+
+#[test]
+fn parses_list_cards() -> Result<()> {
+    let client = JobKoreaClient::new(config());
+    let jobs = client.list_filter().apply(client.parse_html(&list_html())?);
+
+    let titles: Vec<_> = jobs.iter().map(|job| job.title.as_str()).collect();
+    assert_eq!(
+        titles,
+        [
+            "[쿠팡] 프론트엔드 개발자",
+            "웹 프론트엔드 개발자 (신입)",
+            "프론트엔드 엔지니어"
+        ]
+    );
+
+    let job = &jobs[0];
+    assert_eq!(job.company, "쿠팡(주)");
+    assert_eq!(job.experience_years, "경력3년↑");
+    assert_eq!(job.experience.min_years, Some(3));
+    assert_eq!(job.deadline, "~12/10(수)");
+    assert_eq!(job.location, "서울 송파구");
+    assert_eq!(job.region.sigungu.as_deref(), Some("송파구"));
+    assert_eq!(
+        job.salary,
+        Some(Salary::Range {
+            min: Some(5000),
+            max: None
+        })
+    );
+    assert_eq!(job.tech_stack, ["React", "TypeScript", "React Query"]);
+    assert_eq!(
+        job.url,
+        "https://www.jobkorea.co.kr/Recruit/GI_Read/46000001"
+    );
+
+    assert!(jobs[1].experience.accepts_newcomer);
+    assert!(jobs[2].experience.any);
+    Ok(())
+}
+
+#[test]
+fn finds_last_page_from_result_count() {
+    // 총 85건, 페이지당 20건
+    let client = JobKoreaClient::new(config());
+    assert_eq!(client.parse_last_page(&list_html()), Some(5));
+}
+
+#[test]
+fn ignores_pagination_window_with_next_button() {
+    let client = JobKoreaClient::new(config());
+    let html = list_html().replace("총 <strong>85</strong>건", "");
+    assert_eq!(client.parse_last_page(&html), None);
+
+    let html = html.replace(
+        r##"<a class="tplBtn btnPgnNext" href="#"><span>다음</span></a>"##,
+        "",
+    );
+    assert_eq!(client.parse_last_page(&html), Some(4));
+}
+
+#[test]
+fn builds_url_with_duty_and_career_filters() {
+    let client = JobKoreaClient::new(config());
+    assert_eq!(
+        client.build_url(),
+        "https://www.jobkorea.co.kr/Search/?stext=%ED%94%84%EB%A1%A0%ED%8A%B8%EC%97%94%EB%93%9C&tabType=recruit&duty=1000230&careerType=1,2&careerMax=5"
+    );
+
+    // 신입 포함이 아니면 경력 구간을 그대로 보냄
+    let client = JobKoreaClient::new(JobKoreaCrawlConfig {
+        duty: None,
+        min_years: Some(3),
+        max_years: None,
+        ..config()
+    });
+    assert!(client.build_url().ends_with("&tabType=recruit&careerMin=3"));
+}
+
+#[test]
+fn crawls_list_and_detail_pages_from_fixtures() -> Result<()> {
+    let client = JobKoreaClient::new(config());
+    let jobs = client.start_crawl()?.jobs;
+    assert_eq!(jobs.len(), 3);
+
+    let details = client.crawl_job_details(
+        jobs.into_iter().take(1).collect(),
+        &DetailCrawlConfig {
+            thread_count: 1,
+            includes: vec!["GraphQL"],
+            ..Default::default()
+        },
+    )?;
+
+    let job = &details[0];
+    assert_eq!(job.deadline, "2025.12.10");
+    assert_eq!(job.employment_type, "정규직");
+    assert_eq!(
+        job.salary,
+        Some(Salary::Range {
+            min: Some(5500),
+            max: Some(8000)
+        })
+    );
+    assert_eq!(job.description.main_tasks, "쿠팡 커머스 웹 프론트엔드 개발");
+    assert_eq!(job.description.preferred, "GraphQL 사용 경험");
+    assert_eq!(
+        job.description.hiring_process,
+        "서류 - 코딩테스트 - 면접 - 최종합격"
+    );
+    assert!(job.tech_stack.contains(&"GraphQL".to_string()));
+    Ok(())
+}
+
+#[test]
+fn keeps_list_values_when_detail_labels_are_missing() -> Result<()> {
+    // 급여·고용형태 항목이 없는 상세 페이지
+    let dir = std::env::temp_dir().join("jd-crawler-jobkorea-missing-labels");
+    std::fs::create_dir_all(&dir).unwrap();
+    for entry in std::fs::read_dir(fixture_dir()).unwrap() {
+        let path = entry.unwrap().path();
+        let html = std::fs::read_to_string(&path)
+            .unwrap()
+            .replace("<dt>고용형태</dt>", "")
+            .replace("<dd><strong>정규직</strong></dd>", "")
+            .replace("<dt>급여</dt>", "")
+            .replace("<dd>연봉 5,500 ~ 8,000만원</dd>", "");
+        std::fs::write(dir.join(path.file_name().unwrap()), html).unwrap();
+    }
+
+    let client = JobKoreaClient::new(JobKoreaCrawlConfig {
+        fetcher: FetcherKind::Fixture(dir),
+        ..config()
+    });
+    let jobs = client.start_crawl()?.jobs;
+    let details = client.crawl_job_details(
+        jobs.into_iter().take(1).collect(),
+        &DetailCrawlConfig {
+            thread_count: 1,
+            includes: vec![],
+            ..Default::default()
+        },
+    )?;
+
+    let job = &details[0];
+    assert_eq!(job.deadline, "2025.12.10");
+    assert_eq!(job.employment_type, "");
+    assert_eq!(
+        job.salary,
+        Some(Salary::Range {
+            min: Some(5000),
+            max: None
+        })
+    );
+    Ok(())
+}
+
+#[test]
+fn encodes_search_keyword() {
+    let url = JobKoreaClient::new(JobKoreaCrawlConfig {
+        keyword: "C++ & C#",
+        ..JobKoreaCrawlConfig::default()
+    })
+    .build_url();
+
+    assert_eq!(
+        url,
+        "https://www.jobkorea.co.kr/Search/?stext=C%2B%2B+%26+C%23&tabType=recruit"
+    );
+}
@@ -0,0 +1,135 @@
+use jd_crawler::crawler::{DetailCrawler, JobCrawler};
+use jd_crawler::pipeline::Crawler;
+use jd_crawler::{
+    DetailCrawlConfig, FetcherKind, JobListInfiniteScrollCrawler, JumpitClient, JumpitCrawlConfig,
+    JumpitJobCategory, JumpitLocation, Result, Salary,
+};
+use std::path::{Path, PathBuf};
+
+fn fixture_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/jumpit")
+}
+
+fn config() -> JumpitCrawlConfig {
+    JumpitCrawlConfig {
+        category: JumpitJobCategory::Frontend,
+        tech_stacks: vec!["React"],
+        min_years: 0,
+        max_years: 5,
+        location_tags: vec![JumpitLocation::Seoul],
+        total_pages: 2,
+        thread_count: 1,
+        exclude_keywords: vec!["iOS"],
+        locations: vec![],
+        fetcher: FetcherKind::Fixture(fixture_dir()),
+    }
+}
+
+fn list_html() -> String {
+    let path = fixture_dir().join(
+        "jumpit.saramin.co.kr_positions_jobCategory_2_career_0_career_5_techStack_React_locationTag_101000_sort_rsp_rate.html",
+    );
+    std::fs::read_to_string(path).unwrap()
+}
+
+#[test]
+fn parses_list_cards() -> Result<()> {
+    let client = JumpitClient::new(config());
+    let jobs = client.list_filter().apply(client.parse_html(&list_html())?);
+
+    let titles: Vec<_> = jobs.iter().map(|job| job.title.as_str()).collect();
+    assert_eq!(
+        titles,
+        [
+            "Frontend Developer (Product)",
+            "웹 프론트엔드 엔지니어",
+            "프론트엔드 개발자"
+        ]
+    );
+
+    let job = &jobs[0];
+    assert_eq!(job.company, "비바리퍼블리카(토스)");
+    assert_eq!(job.experience_years, "경력 3-7년");
+    assert_eq!(job.experience.min_years, Some(3));
+    assert_eq!(job.experience.max_years, Some(7));
+    assert_eq!(job.deadline, "D-12");
+    assert_eq!(job.location, "서울 강남구");
+    assert_eq!(job.region.sigungu.as_deref(), Some("강남구"));
+    assert_eq!(job.tech_stack, ["React", "TypeScript", "Next.js"]);
+    assert_eq!(job.url, "https://jumpit.saramin.co.kr/position/51001");
+
+    assert!(jobs[1].experience.accepts_newcomer);
+    assert_eq!(jobs[1].deadline, "상시");
+    Ok(())
+}
+
+#[test]
+fn filters_list_cards_by_location() -> Result<()> {
+    let client = JumpitClient::new(JumpitCrawlConfig {
+        exclude_keywords: vec![],
+        locations: vec!["서울 강남·서초", "판교"],
+        ..config()
+    });
+    let jobs = client.list_filter().apply(client.parse_html(&list_html())?);
+
+    let companies: Vec<_> = jobs.iter().map(|job| job.company.as_str()).collect();
+    assert_eq!(
+        companies,
+        ["비바리퍼블리카(토스)", "당근마켓", "라인플러스"]
+    );
+    Ok(())
+}
+
+#[test]
+fn builds_url_with_tech_stack_and_location_tags() {
+    let client = JumpitClient::new(JumpitCrawlConfig {
+        tech_stacks: vec!["React", "C++"],
+        location_tags: vec![JumpitLocation::Seoul, JumpitLocation::Gyeonggi],
+        ..config()
+    });
+    assert_eq!(
+        client.build_url(),
+        "https://jumpit.saramin.co.kr/positions?jobCategory=2&career=0&career=5&techStack=React&techStack=C%2B%2B&locationTag=101000&locationTag=102000&sort=rsp_rate"
+    );
+}
+
+#[test]
+fn crawls_list_and_detail_pages_from_fixtures() -> Result<()> {
+    let client = JumpitClient::new(config());
+    let jobs = client.start_crawl()?.jobs;
+    assert_eq!(jobs.len(), 3);
+
+    let details = client.crawl_job_details(
+        jobs.into_iter().take(1).collect(),
+        &DetailCrawlConfig {
+            thread_count: 1,
+            includes: vec![],
+            ..Default::default()
+        },
+    )?;
+
+    let job = &details[0];
+    assert_eq!(job.deadline, "2025-12-02");
+    assert_eq!(
+        job.salary,
+        Some(Salary::Range {
+            min: Some(6000),
+            max: Some(9000)
+        })
+    );
+    // script 안의 기술(Kotlin)은 무시하고 상세 페이지 기술스택 태그를 덧붙임
+    assert_eq!(
+        job.tech_stack,
+        ["React", "TypeScript", "Next.js", "GraphQL", "Jest"]
+    );
+    assert_eq!(
+        job.description.main_tasks,
+        "토스 앱 내 웹뷰 서비스 개발\n디자인 시스템 컴포넌트 개발 및 유지보수"
+    );
+    assert_eq!(
+        job.description.benefits,
+        "업무 장비 지원\n점심, 저녁 식대 지원"
+    );
+    assert!(job.description.hiring_process.starts_with("서류 전형"));
+    Ok(())
+}
@@ -0,0 +1,156 @@
+use jd_crawler::crawler::{DetailCrawler, JobCrawler};
+use jd_crawler::pipeline::Crawler;
+use jd_crawler::{
+    DetailCrawlConfig, FetcherKind, JobListPaginatedCrawler, ProgrammersClient,
+    ProgrammersCrawlConfig, ProgrammersJobCategory, Result, Salary,
+};
+use std::path::{Path, PathBuf};
+
+fn fixture_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/programmers")
+}
+
+fn config() -> ProgrammersCrawlConfig {
+    ProgrammersCrawlConfig {
+        category: Some(ProgrammersJobCategory::Frontend),
+        min_salary: Some(5000),
+        min_years: Some(0),
+        max_years: Some(5),
+        tags: vec!["React"],
+        total_pages: Some(1),
+        thread_count: 1,
+        exclude_keywords: vec!["Android"],
+        locations: vec![],
+        fetcher: FetcherKind::Fixture(fixture_dir()),
+    }
+}
+
+fn list_html() -> String {
+    let path = fixture_dir().join(
+        "career.programmers.co.kr_job_order_recent_job_category_ids_4_min_salary_5000_min_career_0_max_career_5_tags_React_page_1.html",
+    );
+    std::fs::read_to_string(path).unwrap()
+}
+
+#[test]
+fn parses_list_cards() -> Result<()> {
+    let client = ProgrammersClient::new(config());
+    let jobs = client.list_filter().apply(client.parse_html(&list_html())?);
+
+    let titles: Vec<_> = jobs.iter().map(|job| job.title.as_str()).collect();
+    assert_eq!(
+        titles,
+        [
+            "프론트엔드 개발자 (React)",
+            "웹 프론트엔드 엔지니어",
+            "Frontend Engineer"
+        ]
+    );
+
+    let job = &jobs[0];
+    assert_eq!(job.company, "그렙(주)");
+    assert_eq!(job.experience_years, "경력 3-5년");
+    assert_eq!(job.experience.min_years, Some(3));
+    assert_eq!(job.location, "서울 성동구");
+    assert_eq!(
+        job.salary,
+        Some(Salary::Range {
+            min: Some(5000),
+            max: Some(7000)
+        })
+    );
+    assert_eq!(job.tech_stack, ["React", "TypeScript", "Redux"]);
+    assert_eq!(
+        job.url,
+        "https://career.programmers.co.kr/job_positions/12001"
+    );
+
+    assert_eq!(
+        jobs[1].salary,
+        Some(Salary::Range {
+            min: Some(5500),
+            max: None
+        })
+    );
+    assert!(jobs[1].experience.accepts_newcomer);
+    assert_eq!(jobs[2].salary, Some(Salary::Negotiable));
+    assert!(jobs[2].experience.any);
+    Ok(())
+}
+
+#[test]
+fn finds_last_page_from_result_count() {
+    // 총 125개, 페이지당 20개. 페이지 목록은 3페이지까지만 보임
+    let client = ProgrammersClient::new(config());
+    assert_eq!(client.parse_last_page(&list_html()), Some(7));
+
+    let html = list_html().replace(
+        r#"<div class="total-count">총 <strong>125</strong>개</div>"#,
+        "",
+    );
+    assert_eq!(client.parse_last_page(&html), None);
+}
+
+#[test]
+fn builds_url_with_category_salary_and_tags() {
+    let client = ProgrammersClient::new(config());
+    assert_eq!(
+        client.build_url(),
+        "https://career.programmers.co.kr/job?order=recent&job_category_ids=4&min_salary=5000&min_career=0&max_career=5&tags=React"
+    );
+
+    // 태그는 하나씩 따로 보내고 값은 인코딩
+    let client = ProgrammersClient::new(ProgrammersCrawlConfig {
+        category: None,
+        min_salary: None,
+        min_years: None,
+        max_years: None,
+        tags: vec!["React", "C++"],
+        ..config()
+    });
+    assert_eq!(
+        client.build_url(),
+        "https://career.programmers.co.kr/job?order=recent&tags=React&tags=C%2B%2B"
+    );
+}
+
+#[test]
+fn crawls_list_and_detail_pages_from_fixtures() -> Result<()> {
+    let client = ProgrammersClient::new(config());
+    let jobs = client.start_crawl()?.jobs;
+    assert_eq!(jobs.len(), 3);
+
+    let details = client.crawl_job_details(
+        jobs.into_iter().take(1).collect(),
+        &DetailCrawlConfig {
+            thread_count: 1,
+            includes: vec![],
+            ..Default::default()
+        },
+    )?;
+
+    let job = &details[0];
+    assert_eq!(job.deadline, "2025-12-31");
+    assert_eq!(job.employment_type, "정규직");
+    assert_eq!(
+        job.salary,
+        Some(Salary::Range {
+            min: Some(5000),
+            max: Some(7500)
+        })
+    );
+    assert_eq!(
+        job.tech_stack,
+        ["React", "TypeScript", "Redux", "Emotion", "Storybook"]
+    );
+    assert_eq!(
+        job.description.main_tasks,
+        "프로그래머스 학습 서비스 웹 프론트엔드 개발\n사내 디자인 시스템 고도화"
+    );
+    assert_eq!(job.description.benefits, "자기계발비 연 200만원 지원");
+    assert_eq!(
+        job.description.hiring_process,
+        "서류 전형 - 코딩 테스트 - 기술 면접 - 최종 면접"
+    );
+    Ok(())
+}
@@ -0,0 +1,113 @@
+use jd_crawler::crawler::{DetailCrawler, JobCrawler};
+use jd_crawler::pipeline::Crawler;
+use jd_crawler::{
+    DetailCrawlConfig, FetcherKind, JobListInfiniteScrollCrawler, RememberClient,
+    RememberCrawlConfig, Result, Salary,
+};
+use std::path::{Path, PathBuf};
+
+fn fixture_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/remember")
+}
+
+fn config() -> RememberCrawlConfig {
+    RememberCrawlConfig {
+        keyword: "프론트엔드",
+        min_years: 5,
+        max_years: 15,
+        total_pages: 2,
+        thread_count: 1,
+        exclude_keywords: vec!["Android"],
+        locations: vec![],
+        fetcher: FetcherKind::Fixture(fixture_dir()),
+    }
+}
+
+fn list_html() -> String {
+    let path = fixture_dir().join(
+        "career.rememberapp.co.kr_job_postings_keyword_프론트엔드_min_experience_5_max_experience_15.html",
+    );
+    std::fs::read_to_string(path).unwrap()
+}
+
+#[test]
+fn parses_list_cards() -> Result<()> {
+    let client = RememberClient::new(config());
+    let jobs = client.list_filter().apply(client.parse_html(&list_html())?);
+
+    let titles: Vec<_> = jobs.iter().map(|job| job.title.as_str()).collect();
+    assert_eq!(
+        titles,
+        [
+            "프론트엔드 리드 (Tech Lead)",
+            "시니어 웹 프론트엔드 개발자",
+            "Staff Frontend Engineer"
+        ]
+    );
+
+    let job = &jobs[0];
+    assert_eq!(job.company, "네이버웹툰");
+    assert_eq!(job.experience_years, "경력 8~15년");
+    assert_eq!(job.experience.min_years, Some(8));
+    assert_eq!(job.experience.max_years, Some(15));
+    assert_eq!(job.deadline, "D-14");
+    assert_eq!(job.location, "경기 성남시 분당구");
+    assert_eq!(job.company_size.as_deref(), Some("1,000명 이상"));
+    assert_eq!(
+        job.url,
+        "https://career.rememberapp.co.kr/job/posting/210001"
+    );
+
+    assert_eq!(jobs[1].experience.min_years, Some(7));
+    assert_eq!(jobs[1].experience.max_years, None);
+    assert_eq!(jobs[1].company_size.as_deref(), Some("300~1,000명"));
+    assert_eq!(jobs[2].company_size, None);
+    Ok(())
+}
+
+#[test]
+fn builds_url_with_keyword_and_experience() {
+    let client = RememberClient::new(RememberCrawlConfig {
+        keyword: "R&D",
+        min_years: 0,
+        max_years: 3,
+        ..config()
+    });
+    assert_eq!(
+        client.build_url(),
+        "https://career.rememberapp.co.kr/job/postings?keyword=R%26D&min_experience=0&max_experience=3"
+    );
+}
+
+#[test]
+fn crawls_list_and_detail_pages_from_fixtures() -> Result<()> {
+    let client = RememberClient::new(config());
+    let jobs = client.start_crawl()?.jobs;
+    assert_eq!(jobs.len(), 3);
+
+    let details = client.crawl_job_details(
+        jobs.into_iter().take(1).collect(),
+        &DetailCrawlConfig {
+            thread_count: 1,
+            includes: vec![],
+            ..Default::default()
+        },
+    )?;
+
+    let job = &details[0];
+    assert_eq!(job.employment_type, "정규직");
+    assert_eq!(
+        job.salary,
+        Some(Salary::Range {
+            min: Some(10000),
+            max: Some(15000)
+        })
+    );
+    assert_eq!(job.company_size.as_deref(), Some("1,000명 이상"));
+    assert_eq!(job.tech_stack, ["React", "TypeScript", "Next.js"]);
+    assert_eq!(
+        job.description.main_tasks,
+        "웹툰 글로벌 웹 서비스 프론트엔드 아키텍처 설계\n프론트엔드 조직 기술 리딩"
+    );
+    Ok(())
+}
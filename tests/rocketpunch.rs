@@ -0,0 +1,142 @@
+use jd_crawler::crawler::{DetailCrawler, JobCrawler};
+use jd_crawler::pipeline::Crawler;
+use jd_crawler::{
+    DetailCrawlConfig, FetcherKind, JobListPaginatedCrawler, Result, RocketpunchCareer,
+    RocketpunchClient, RocketpunchCrawlConfig, RocketpunchJobRole, Salary,
+};
+use std::path::{Path, PathBuf};
+
+fn fixture_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/rocketpunch")
+}
+
+fn config() -> RocketpunchCrawlConfig {
+    RocketpunchCrawlConfig {
+        role: RocketpunchJobRole::Frontend,
+        career: Some(RocketpunchCareer::Experienced),
+        location: Some("서울특별시"),
+        total_pages: Some(1),
+        thread_count: 1,
+        exclude_keywords: vec!["안드로이드"],
+        locations: vec![],
+        fetcher: FetcherKind::Fixture(fixture_dir()),
+    }
+}
+
+fn list_html() -> String {
+    let path = fixture_dir().join(
+        "www.rocketpunch.com_jobs_job_프론트엔드_career_type_2_location_서울특별시_page_1.html",
+    );
+    std::fs::read_to_string(path).unwrap()
+}
+
+#[test]
+fn parses_company_cards_into_jobs() -> Result<()> {
+    let client = RocketpunchClient::new(config());
+    let jobs = client.list_filter().apply(client.parse_html(&list_html())?);
+
+    let titles: Vec<_> = jobs.iter().map(|job| job.title.as_str()).collect();
+    assert_eq!(
+        titles,
+        [
+            "프론트엔드 개발자",
+            "웹 프론트엔드 엔지니어",
+            "프론트엔드 엔지니어"
+        ]
+    );
+
+    let job = &jobs[0];
+    assert_eq!(job.company, "플렉스");
+    assert_eq!(job.company_stage.as_deref(), Some("성장기"));
+    assert_eq!(job.investment_round.as_deref(), Some("시리즈 B"));
+    assert_eq!(job.experience_years, "경력 3-7년");
+    assert_eq!(job.experience.max_years, Some(7));
+    assert_eq!(job.deadline, "~ 12/15");
+    assert_eq!(job.location, "서울 강남구 역삼동");
+    assert_eq!(job.region.dong.as_deref(), Some("역삼동"));
+    assert_eq!(job.employment_type, "정규직");
+    assert_eq!(
+        job.salary,
+        Some(Salary::Range {
+            min: Some(5000),
+            max: Some(8000)
+        })
+    );
+    assert_eq!(
+        job.url,
+        "https://www.rocketpunch.com/jobs/98001/프론트엔드-개발자"
+    );
+
+    assert_eq!(jobs[1].salary, Some(Salary::Negotiable));
+    assert!(jobs[1].experience.accepts_newcomer);
+    assert_eq!(jobs[2].investment_round, None);
+    assert_eq!(jobs[2].employment_type, "계약직");
+    Ok(())
+}
+
+#[test]
+fn finds_last_page_from_pagination() {
+    let client = RocketpunchClient::new(config());
+    assert_eq!(client.parse_last_page(&list_html()), Some(7));
+
+    // 마지막 페이지 번호 없이 "다음" 링크만 있으면 알 수 없음
+    let html = list_html().replace(
+        r##"<a class="item" href="#">7</a>"##,
+        r##"<a class="item" href="#">3</a><a class="item" href="#">›</a>"##,
+    );
+    assert_eq!(client.parse_last_page(&html), None);
+}
+
+#[test]
+fn builds_url_with_career_and_location() {
+    let client = RocketpunchClient::new(RocketpunchCrawlConfig {
+        location: Some("경기도 성남시"),
+        ..config()
+    });
+    assert_eq!(
+        client.build_url(),
+        "https://www.rocketpunch.com/jobs?job=프론트엔드&career_type=2&location=%EA%B2%BD%EA%B8%B0%EB%8F%84+%EC%84%B1%EB%82%A8%EC%8B%9C"
+    );
+
+    let client = RocketpunchClient::new(RocketpunchCrawlConfig {
+        career: None,
+        location: None,
+        ..config()
+    });
+    assert_eq!(
+        client.build_url(),
+        "https://www.rocketpunch.com/jobs?job=프론트엔드"
+    );
+}
+
+#[test]
+fn crawls_list_and_detail_pages_from_fixtures() -> Result<()> {
+    let client = RocketpunchClient::new(config());
+    let jobs = client.start_crawl()?.jobs;
+    assert_eq!(jobs.len(), 3);
+
+    let details = client.crawl_job_details(
+        jobs.into_iter().take(1).collect(),
+        &DetailCrawlConfig {
+            thread_count: 1,
+            includes: vec![],
+            ..Default::default()
+        },
+    )?;
+
+    let job = &details[0];
+    assert_eq!(job.deadline, "2025-12-15");
+    // 상세 페이지에 고용형태 항목이 없으면 목록 카드의 값을 유지
+    assert_eq!(job.employment_type, "정규직");
+    assert_eq!(job.investment_round.as_deref(), Some("시리즈 B"));
+    assert_eq!(
+        job.tech_stack,
+        ["React", "TypeScript", "Next.js", "Emotion", "GraphQL"]
+    );
+    assert_eq!(
+        job.description.main_tasks,
+        "flex 웹 애플리케이션 개발\n급여, 근태 관리 기능 프론트엔드 개발"
+    );
+    assert_eq!(job.description.benefits, "스톡옵션 부여");
+    Ok(())
+}
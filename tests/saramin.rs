@@ -0,0 +1,131 @@
+use jd_crawler::pipeline::Crawler;
+use jd_crawler::{
+    FetcherKind, Result, Salary, SaraminCareer, SaraminClient, SaraminCompanyType,
+    SaraminCrawlConfig, SaraminEducation, SaraminEmploymentType, SaraminJobCategory, SaraminRegion,
+    SaraminSort,
+};
+use std::path::{Path, PathBuf};
+
+fn fixture_dir(site: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(site)
+}
+
+fn config() -> SaraminCrawlConfig {
+    SaraminCrawlConfig {
+        category: SaraminJobCategory::Keyword("React 개발자"),
+        careers: vec![SaraminCareer::Newcomer],
+        min_years: Some(1),
+        max_years: Some(5),
+        education: Some(SaraminEducation::University),
+        employment_types: vec![
+            SaraminEmploymentType::Permanent,
+            SaraminEmploymentType::Intern,
+        ],
+        regions: vec![SaraminRegion::Seoul, SaraminRegion::Custom(101050)],
+        min_salary: Some(4000),
+        company_types: vec![SaraminCompanyType::Large],
+        sort: Some(SaraminSort::Latest),
+        total_pages: Some(1),
+        thread_count: 1,
+        exclude_keywords: vec![],
+        locations: vec![],
+        fetcher: FetcherKind::Fixture(fixture_dir("saramin")),
+    }
+}
+
+#[test]
+fn crawls_with_search_filters() -> Result<()> {
+    // 검색 조건이 URL에 빠짐없이 들어가야 해당 URL로 저장된 fixture를 찾을 수 있음
+    let jobs = SaraminClient::new(config()).start_crawl()?.jobs;
+
+    let titles: Vec<_> = jobs.iter().map(|job| job.title.as_str()).collect();
+    assert_eq!(
+        titles,
+        ["React 프론트엔드 개발자", "React Native 앱 개발 인턴"]
+    );
+
+    let job = &jobs[0];
+    assert_eq!(job.company, "(주)우아한형제들");
+    assert_eq!(job.location, "서울 강남구");
+    assert_eq!(
+        job.salary,
+        Some(Salary::Range {
+            min: Some(4500),
+            max: Some(6000)
+        })
+    );
+    assert_eq!(
+        job.url,
+        "https://www.saramin.co.kr/zf_user/jobs/relay/view?rec_idx=6101"
+    );
+    assert_eq!(jobs[1].salary, Some(Salary::Negotiable));
+    Ok(())
+}
+
+#[test]
+fn default_config_keeps_plain_keyword_search() -> Result<()> {
+    let jobs = SaraminClient::new(SaraminCrawlConfig {
+        fetcher: FetcherKind::Fixture(fixture_dir("golden")),
+        ..SaraminCrawlConfig::default()
+    })
+    .start_crawl()?
+    .jobs;
+
+    assert_eq!(jobs.len(), 4);
+    Ok(())
+}
+
+#[test]
+fn finds_last_page_from_result_count() -> Result<()> {
+    // 총 85건, 페이지당 40건이므로 3페이지까지 수집
+    let jobs = SaraminClient::new(SaraminCrawlConfig {
+        category: SaraminJobCategory::Keyword("Rust"),
+        total_pages: None,
+        thread_count: 2,
+        fetcher: FetcherKind::Fixture(fixture_dir("saramin")),
+        ..SaraminCrawlConfig::default()
+    })
+    .start_crawl()?
+    .jobs;
+
+    let companies: Vec<_> = jobs.iter().map(|job| job.company.as_str()).collect();
+    assert_eq!(companies, ["(주)러스트랩", "(주)페리스", "(주)카고"]);
+    Ok(())
+}
+
+#[test]
+fn total_pages_caps_detected_last_page() -> Result<()> {
+    let jobs = SaraminClient::new(SaraminCrawlConfig {
+        category: SaraminJobCategory::Keyword("Rust"),
+        total_pages: Some(2),
+        fetcher: FetcherKind::Fixture(fixture_dir("saramin")),
+        ..SaraminCrawlConfig::default()
+    })
+    .start_crawl()?
+    .jobs;
+
+    assert_eq!(jobs.len(), 2);
+    Ok(())
+}
+
+#[test]
+fn encodes_search_keyword() {
+    let url = |word| {
+        SaraminClient::new(SaraminCrawlConfig {
+            category: SaraminJobCategory::Keyword(word),
+            ..SaraminCrawlConfig::default()
+        })
+        .build_url()
+    };
+
+    let cases = [
+        ("C#", "?searchword=C%23"),
+        ("C++", "?searchword=C%2B%2B"),
+        ("R&D 개발", "?searchword=R%26D+%EA%B0%9C%EB%B0%9C"),
+    ];
+    for (word, expected) in cases {
+        assert!(url(word).ends_with(expected), "{}: {}", word, url(word));
+    }
+}
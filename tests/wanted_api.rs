@@ -0,0 +1,218 @@
+use jd_crawler::pipeline::Crawler;
+use jd_crawler::{
+    CrawlPipeline, Error, FetcherKind, Result, WantedClient, WantedCrawlConfig, WantedCrawlMode,
+    WantedJobCategory, WantedJobSort, WantedJobSubcategory, WantedLocation,
+};
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+/// 원티드 목록 API 대신 응답하는 로컬 서버. offset 별 JSON fixture를 돌려주고 받은 요청 경로를 기록함.
+/// `failing_offset`의 요청에는 503으로 응답
+fn spawn_api_server(
+    status: &'static str,
+    failing_offset: Option<&'static str>,
+) -> (String, Arc<Mutex<Vec<String>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = format!("http://{}", listener.local_addr().unwrap());
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let recorded = Arc::clone(&requests);
+
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut reader = BufReader::new(&stream);
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+            loop {
+                let mut header = String::new();
+                reader.read_line(&mut header).unwrap();
+                if header.trim().is_empty() {
+                    break;
+                }
+            }
+
+            let path = request_line
+                .split_whitespace()
+                .nth(1)
+                .unwrap_or_default()
+                .to_string();
+            let offset = path
+                .split(['?', '&'])
+                .find_map(|param| param.strip_prefix("offset="))
+                .unwrap_or("0");
+            let fixture = Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join(format!("tests/fixtures/wanted_api/offset_{}.json", offset));
+            let body = std::fs::read_to_string(fixture)
+                .unwrap_or_else(|_| r#"{"data": [], "links": {"next": null}}"#.to_string());
+            let status = if failing_offset == Some(offset) {
+                "503 Service Unavailable"
+            } else {
+                status
+            };
+            recorded.lock().unwrap().push(path);
+
+            write!(
+                stream,
+                "HTTP/1.1 {}\r\nContent-Type: application/json; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            )
+            .unwrap();
+        }
+    });
+
+    (address, requests)
+}
+
+fn config() -> WantedCrawlConfig {
+    WantedCrawlConfig {
+        total_pages: 5,
+        exclude_keywords: vec!["iOS"],
+        fetcher: FetcherKind::Http,
+        mode: WantedCrawlMode::Api,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn rejects_api_mode_with_chrome_fetcher() {
+    let result = WantedClient::new(WantedCrawlConfig {
+        fetcher: FetcherKind::Chrome,
+        ..config()
+    })
+    .start_crawl();
+
+    assert!(
+        matches!(&result, Err(Error::Config { message, .. }) if message.contains("Http")),
+        "{:?}",
+        result
+    );
+}
+
+#[test]
+fn collects_jobs_from_list_api() -> Result<()> {
+    let (address, requests) = spawn_api_server("200 OK", None);
+    let client = WantedClient::new(config()).with_base_url(&address);
+
+    let jobs = client.start_crawl()?.jobs;
+
+    let titles: Vec<_> = jobs.iter().map(|job| job.title.as_str()).collect();
+    assert_eq!(
+        titles,
+        [
+            "프론트엔드 개발자 (Next.js)",
+            "웹 프론트엔드 엔지니어 (신입)",
+            "Frontend Engineer"
+        ]
+    );
+
+    let job = &jobs[0];
+    assert_eq!(job.company, "비바리퍼블리카(토스)");
+    assert_eq!(job.company_id.as_deref(), Some("13124"));
+    assert_eq!(job.deadline, "2025-12-15");
+    assert_eq!(job.experience_years, "경력 3-7년");
+    assert_eq!(job.experience.max_years, Some(7));
+    assert_eq!(job.location, "서울 강남구");
+    assert_eq!(job.region.sigungu.as_deref(), Some("강남구"));
+    assert_eq!(job.tech_stack, ["React", "TypeScript", "Next.js"]);
+    assert_eq!(job.url, format!("{}/wd/240001", address));
+
+    let newcomer = &jobs[1];
+    assert_eq!(newcomer.deadline, "상시채용");
+    assert!(newcomer.experience.accepts_newcomer);
+    assert_eq!(newcomer.experience.max_years, Some(2));
+    assert_eq!(newcomer.tech_stack, ["JavaScript", "웹접근성"]);
+    assert_eq!(newcomer.region.sigungu.as_deref(), Some("성남시 분당구"));
+
+    assert_eq!(jobs[2].experience_years, "신입");
+
+    // links.next가 없으면 total_pages보다 먼저 멈춤
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests.len(), 2);
+    assert!(
+        requests[0].starts_with(
+            "/api/chaos/navigation/v1/results?job_group_id=518&job_ids=669&country=kr"
+        )
+    );
+    assert!(requests[1].ends_with("&limit=20&offset=20"));
+    Ok(())
+}
+
+#[test]
+fn api_due_time_feeds_deadline_normalization() -> Result<()> {
+    let (address, _) = spawn_api_server("200 OK", None);
+    let output = std::env::temp_dir().join("jd-crawler-wanted-api.csv");
+
+    CrawlPipeline::new()
+        .with_crawl_date(chrono::NaiveDate::from_ymd_opt(2025, 11, 20).unwrap())
+        .crawl(WantedClient::new(config()).with_base_url(&address))?
+        .filter(|job| job.deadline_date.is_some())
+        .save(output.to_str().unwrap());
+
+    let csv = std::fs::read_to_string(&output).unwrap();
+    assert_eq!(csv.lines().count(), 3);
+    assert!(csv.contains(",13124,경력 3-7년,"));
+    assert!(csv.contains(",2025-12-15,fixed,"));
+    Ok(())
+}
+
+#[test]
+fn search_filters_are_sent_to_list_api() -> Result<()> {
+    let (address, requests) = spawn_api_server("200 OK", None);
+    let client = WantedClient::new(WantedCrawlConfig {
+        category: WantedJobCategory::find("개발").unwrap(),
+        subcategory: WantedJobSubcategory::Custom(1027),
+        sort: WantedJobSort::Latest,
+        location_tags: vec![
+            WantedLocation::Seoul,
+            WantedLocation::Custom("gyeonggi.seongnam-si"),
+        ],
+        skill_tags: vec![1541, 1562],
+        total_pages: 1,
+        ..config()
+    })
+    .with_base_url(&address);
+
+    client.start_crawl()?;
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(
+        requests[0],
+        "/api/chaos/navigation/v1/results?job_group_id=518&job_ids=1027&country=kr&job_sort=job.latest_order&years=0&years=5&locations=seoul.all&locations=gyeonggi.seongnam-si&skill_tags=1541&skill_tags=1562&limit=20&offset=0"
+    );
+    Ok(())
+}
+
+#[test]
+fn failed_api_page_is_recorded_and_skipped() -> Result<()> {
+    let (address, requests) = spawn_api_server("200 OK", Some("0"));
+    let client = WantedClient::new(config()).with_base_url(&address);
+
+    let output = client.start_crawl()?;
+
+    assert_eq!(requests.lock().unwrap().len(), 2);
+    assert!(output.pages[0].error.is_some());
+    assert_eq!(output.pages[1].error, None);
+    assert!(!output.jobs.is_empty());
+    Ok(())
+}
+
+#[test]
+fn rate_limited_api_pages_are_reported_as_blocked() -> Result<()> {
+    let (address, _) = spawn_api_server("429 Too Many Requests", None);
+    let client = WantedClient::new(config()).with_base_url(&address);
+
+    let output = client.start_crawl()?;
+
+    assert!(output.jobs.is_empty());
+    assert_eq!(output.pages.len(), 5);
+    assert!(output.pages.iter().all(|page| {
+        page.error
+            .as_deref()
+            .is_some_and(|e| e.contains("요청 차단"))
+    }));
+    Ok(())
+}
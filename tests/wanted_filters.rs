@@ -0,0 +1,136 @@
+use jd_crawler::{Error, WantedClient, WantedCrawlConfig, WantedJobCategory, WantedJobSubcategory};
+
+#[test]
+fn finds_categories_by_name_alias_or_code() {
+    assert_eq!(
+        WantedJobCategory::find("개발"),
+        Some(WantedJobCategory::Development)
+    );
+    assert_eq!(
+        WantedJobCategory::find("Design"),
+        Some(WantedJobCategory::Design)
+    );
+    assert_eq!(
+        WantedJobCategory::find("507"),
+        Some(WantedJobCategory::Business)
+    );
+    assert_eq!(WantedJobCategory::find("없는 직군"), None);
+}
+
+#[test]
+fn finds_subcategories_by_name_alias_or_code() {
+    assert_eq!(
+        WantedJobSubcategory::find("프론트엔드 개발자"),
+        Some(WantedJobSubcategory::Frontend)
+    );
+    assert_eq!(
+        WantedJobSubcategory::find("devops"),
+        Some(WantedJobSubcategory::DevOps)
+    );
+    assert_eq!(
+        WantedJobSubcategory::find("PM·PO"),
+        Some(WantedJobSubcategory::ProductOwner)
+    );
+    assert_eq!(
+        WantedJobSubcategory::find("872"),
+        Some(WantedJobSubcategory::Backend)
+    );
+    // 직군 이름은 직무로 찾지 않음
+    assert_eq!(WantedJobSubcategory::find("개발"), None);
+}
+
+#[test]
+fn unknown_codes_become_custom_variants() {
+    // 직무표에는 있지만 열거형에는 없는 직무
+    assert_eq!(
+        WantedJobSubcategory::find("블록체인"),
+        Some(WantedJobSubcategory::Custom(1027))
+    );
+    assert_eq!(
+        WantedJobSubcategory::from_code(99999),
+        WantedJobSubcategory::Custom(99999)
+    );
+    assert_eq!(WantedJobSubcategory::Custom(99999).to_code(), Some(99999));
+    assert_eq!(
+        WantedJobCategory::from_code(518),
+        WantedJobCategory::Development
+    );
+}
+
+#[test]
+fn subcategories_know_their_category() {
+    assert_eq!(
+        WantedJobSubcategory::Security.category(),
+        Some(WantedJobCategory::Development)
+    );
+    assert_eq!(
+        WantedJobSubcategory::UxDesigner.category(),
+        Some(WantedJobCategory::Design)
+    );
+    assert_eq!(
+        WantedJobSubcategory::find("게임 서버 개발자").and_then(|job| job.category()),
+        Some(WantedJobCategory::Game)
+    );
+    assert_eq!(WantedJobSubcategory::Custom(99999).category(), None);
+}
+
+#[test]
+fn named_variants_come_from_category_table() {
+    // 코드와 직군은 data/wanted_categories.txt에서만 읽음
+    for subcategory in [
+        WantedJobSubcategory::Frontend,
+        WantedJobSubcategory::Backend,
+        WantedJobSubcategory::Web,
+        WantedJobSubcategory::Android,
+        WantedJobSubcategory::IOS,
+        WantedJobSubcategory::SoftwareEngineer,
+        WantedJobSubcategory::CrossPlatform,
+        WantedJobSubcategory::Java,
+        WantedJobSubcategory::Python,
+        WantedJobSubcategory::NodeJs,
+        WantedJobSubcategory::DataEngineer,
+        WantedJobSubcategory::DataScientist,
+        WantedJobSubcategory::MachineLearning,
+        WantedJobSubcategory::BigData,
+        WantedJobSubcategory::DevOps,
+        WantedJobSubcategory::SystemNetwork,
+        WantedJobSubcategory::Security,
+        WantedJobSubcategory::DBA,
+        WantedJobSubcategory::Embedded,
+        WantedJobSubcategory::QA,
+        WantedJobSubcategory::WebPublisher,
+        WantedJobSubcategory::ProductManager,
+        WantedJobSubcategory::DevelopmentManager,
+        WantedJobSubcategory::UxDesigner,
+        WantedJobSubcategory::UiDesigner,
+        WantedJobSubcategory::ServicePlanner,
+        WantedJobSubcategory::ProductOwner,
+    ] {
+        assert_eq!(
+            subcategory.to_code().map(WantedJobSubcategory::from_code),
+            Some(subcategory)
+        );
+        assert!(subcategory.category().is_some(), "{subcategory:?}");
+    }
+    assert_eq!(WantedJobSubcategory::Frontend.to_code(), Some(669));
+    assert_eq!(WantedJobCategory::Game.to_code(), Some(959));
+}
+
+#[test]
+fn rejects_subcategory_from_another_category() {
+    let client = WantedClient::new(WantedCrawlConfig {
+        category: WantedJobCategory::Development,
+        subcategory: WantedJobSubcategory::UxDesigner,
+        ..Default::default()
+    });
+    let error = client.build_url().unwrap_err();
+    assert!(matches!(error, Error::Config { .. }), "{error}");
+
+    // 직무표에 없는 직무는 직군을 알 수 없어 그대로 사용
+    let client = WantedClient::new(WantedCrawlConfig {
+        category: WantedJobCategory::Design,
+        subcategory: WantedJobSubcategory::Custom(99999),
+        ..Default::default()
+    });
+    assert!(client.build_url().unwrap().contains("/wdlist/511/99999?"));
+}
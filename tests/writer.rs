@@ -0,0 +1,58 @@
+use chrono::Utc;
+use jd_crawler::{CompanyAttribute, Job, Result, save_to_csv};
+use std::path::Path;
+
+/// 마지막 4개 컬럼을 뒤에서부터
+fn tail(record: &csv::StringRecord) -> Vec<&str> {
+    record.iter().rev().take(4).collect()
+}
+
+fn attribute(key: &str, value: &str, source: &str) -> CompanyAttribute {
+    CompanyAttribute {
+        key: key.to_string(),
+        value: value.to_string(),
+        source: source.to_string(),
+        fetched_at: Utc::now(),
+    }
+}
+
+#[test]
+fn writes_company_attributes_as_columns_before_url() -> Result<()> {
+    let mut naver = Job {
+        title: "프론트엔드 개발자".to_string(),
+        company: "네이버".to_string(),
+        url: "https://example.com/1".to_string(),
+        ..Default::default()
+    };
+    naver.set_attribute(attribute("rating", "3.7", "블라인드"));
+    naver.set_attribute(attribute("headcount", "4000", "리멤버"));
+    // 같은 키는 나중 값으로 교체
+    naver.set_attribute(attribute("rating", "3.8", "블라인드"));
+
+    let mut toss = Job {
+        title: "백엔드 개발자".to_string(),
+        company: "토스".to_string(),
+        url: "https://example.com/2".to_string(),
+        ..Default::default()
+    };
+    toss.set_attribute(attribute("funding", "시리즈 G", "더브이씨"));
+
+    let output = Path::new(env!("CARGO_TARGET_TMPDIR")).join("attributes.csv");
+    save_to_csv(&[naver, toss], output.to_str().unwrap())?;
+
+    let mut reader = csv::Reader::from_path(&output).unwrap();
+    let headers = reader.headers().unwrap().clone();
+    let columns = tail(&headers);
+    assert_eq!(columns, vec!["url", "funding", "headcount", "rating"]);
+
+    let rows: Vec<csv::StringRecord> = reader.records().map(|row| row.unwrap()).collect();
+    assert_eq!(
+        tail(&rows[0]),
+        vec!["https://example.com/1", "", "4000", "3.8"]
+    );
+    assert_eq!(
+        tail(&rows[1]),
+        vec!["https://example.com/2", "시리즈 G", "", ""]
+    );
+    Ok(())
+}